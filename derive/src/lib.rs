@@ -0,0 +1,235 @@
+//! Derive macros for [`iced_table`](https://docs.rs/iced_table)
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitFloat, LitStr};
+
+/// Derives the columns of a row struct, one for each of its named fields.
+///
+/// See `iced_table::Column` for the attributes it accepts.
+#[proc_macro_derive(Column, attributes(column))]
+pub fn derive_column(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct Field {
+    ident: Ident,
+    title: String,
+    width: f32,
+    format: Option<LitStr>,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let row = &input.ident;
+    let vis = &input.vis;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "columns can't be derived for generic rows",
+        ));
+    }
+
+    let mut name = format_ident!("{row}Column");
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("column"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let value: LitStr = meta.value()?.parse()?;
+                name = Ident::new(&value.value(), value.span());
+
+                Ok(())
+            } else {
+                Err(meta.error("expected `name`"))
+            }
+        })?;
+    }
+
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    "columns can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "columns can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut fields = vec![];
+
+    for field in named {
+        let ident = field.ident.clone().expect("named field");
+
+        let mut title = title(&ident);
+        let mut width = 100.0;
+        let mut format = None;
+        let mut skip = false;
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("column"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("title") {
+                    title = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("width") {
+                    width = meta.value()?.parse::<LitFloat>()?.base10_parse()?;
+                } else if meta.path.is_ident("format") {
+                    format = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                } else {
+                    return Err(meta.error("expected `title`, `width`, `format` or `skip`"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if !skip {
+            fields.push(Field {
+                ident,
+                title,
+                width,
+                format,
+            });
+        }
+    }
+
+    if fields.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "columns can't be derived without any field left",
+        ));
+    }
+
+    let indices = (0..fields.len()).collect::<Vec<_>>();
+    let titles = fields.iter().map(|field| &field.title);
+    let widths = fields.iter().map(|field| field.width).collect::<Vec<_>>();
+    let texts = fields.iter().map(|field| {
+        let ident = &field.ident;
+
+        match &field.format {
+            Some(format) => quote! { format!(#format, row.#ident) },
+            None => quote! { row.#ident.to_string() },
+        }
+    });
+
+    let doc = format!("The columns derived from the fields of [`{row}`].");
+    let core = quote! { ::iced_table::__derive::iced_core };
+    let widget = quote! { ::iced_table::__derive::iced_widget };
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #vis struct #name {
+            index: usize,
+            width: f32,
+        }
+
+        impl #name {
+            /// Returns all the columns, in the order of their fields.
+            #vis fn all() -> Vec<Self> {
+                vec![#(Self { index: #indices, width: #widths }),*]
+            }
+
+            /// Returns the title of the column, shown in its header.
+            #vis fn title(&self) -> &'static str {
+                [#(#titles),*][self.index]
+            }
+
+            /// Returns the text of the cell of the row.
+            #vis fn text(&self, row: &#row) -> String {
+                match self.index {
+                    #(#indices => #texts,)*
+                    _ => unreachable!(),
+                }
+            }
+
+            /// Sets the width of a resized column, as emitted by
+            /// [`on_column_resize`](::iced_table::Table::on_column_resize).
+            #vis fn set_width(&mut self, width: f32) {
+                self.width = width;
+            }
+        }
+
+        impl<'a, Message, Theme, Renderer> ::iced_table::table::Column<'a, Message, Theme, Renderer>
+            for #name
+        where
+            Theme: #widget::text::Catalog + 'a,
+            Renderer: #core::text::Renderer + 'a,
+        {
+            type Row = #row;
+
+            fn header(&'a self, _col_index: usize) -> #core::Element<'a, Message, Theme, Renderer> {
+                #widget::Text::new(self.title()).into()
+            }
+
+            fn cell(
+                &'a self,
+                _col_index: usize,
+                _row_index: usize,
+                row: &'a Self::Row,
+            ) -> #core::Element<'a, Message, Theme, Renderer> {
+                #widget::Text::new(self.text(row)).into()
+            }
+
+            fn cell_text(
+                &self,
+                _col_index: usize,
+                _row_index: usize,
+                row: &Self::Row,
+            ) -> Option<String> {
+                Some(self.text(row))
+            }
+
+            fn is_plain(&self) -> bool {
+                true
+            }
+
+            fn default_width(&self) -> Option<f32> {
+                Some([#(#widths),*][self.index])
+            }
+
+            fn width(&self) -> f32 {
+                self.width
+            }
+        }
+    })
+}
+
+/// Turns the name of a field, such as `first_name`, into a title, such as `First Name`.
+fn title(ident: &Ident) -> String {
+    let name = ident.to_string();
+
+    name.trim_start_matches("r#")
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
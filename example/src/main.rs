@@ -1,10 +1,9 @@
 use std::fmt;
 
 use iced::widget::{
-    button, checkbox, column, container, horizontal_space, pick_list, responsive, scrollable, text,
-    text_input,
+    button, checkbox, column, container, horizontal_space, pick_list, responsive, text, text_input,
 };
-use iced::{Element, Length, Renderer, Task, Theme};
+use iced::{alignment, Element, Length, Renderer, Task, Theme};
 use iced_table::table;
 
 fn main() {
@@ -16,9 +15,7 @@ fn main() {
 
 #[derive(Debug, Clone)]
 enum Message {
-    SyncHeader(scrollable::AbsoluteOffset),
-    Resizing(usize, f32),
-    Resized,
+    Resized(usize, f32),
     ResizeColumnsEnabled(bool),
     FooterEnabled(bool),
     MinWidthEnabled(bool),
@@ -32,9 +29,6 @@ enum Message {
 struct App {
     columns: Vec<Column>,
     rows: Vec<Row>,
-    header: scrollable::Id,
-    body: scrollable::Id,
-    footer: scrollable::Id,
     resize_columns_enabled: bool,
     footer_enabled: bool,
     min_width_enabled: bool,
@@ -52,9 +46,6 @@ impl Default for App {
                 Column::new(ColumnKind::Delete),
             ],
             rows: (0..50).map(Row::generate).collect(),
-            header: scrollable::Id::unique(),
-            body: scrollable::Id::unique(),
-            footer: scrollable::Id::unique(),
             resize_columns_enabled: true,
             footer_enabled: true,
             min_width_enabled: true,
@@ -74,22 +65,11 @@ impl App {
 
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
-            Message::SyncHeader(offset) => {
-                return Task::batch(vec![
-                    scrollable::scroll_to(self.header.clone(), offset),
-                    scrollable::scroll_to(self.footer.clone(), offset),
-                ])
-            }
-            Message::Resizing(index, offset) => {
+            Message::Resized(index, width) => {
                 if let Some(column) = self.columns.get_mut(index) {
-                    column.resize_offset = Some(offset);
+                    column.width = width;
                 }
             }
-            Message::Resized => self.columns.iter_mut().for_each(|column| {
-                if let Some(offset) = column.resize_offset.take() {
-                    column.width += offset;
-                }
-            }),
             Message::ResizeColumnsEnabled(enabled) => self.resize_columns_enabled = enabled,
             Message::FooterEnabled(enabled) => self.footer_enabled = enabled,
             Message::MinWidthEnabled(enabled) => self.min_width_enabled = enabled,
@@ -123,21 +103,15 @@ impl App {
         Task::none()
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let table = responsive(|size| {
-            let mut table = table(
-                self.header.clone(),
-                self.body.clone(),
-                &self.columns,
-                &self.rows,
-                Message::SyncHeader,
-            );
+            let mut table = table(&self.columns, &self.rows);
 
             if self.resize_columns_enabled {
-                table = table.on_column_resize(Message::Resizing, Message::Resized);
+                table = table.on_column_resize(Message::Resized);
             }
             if self.footer_enabled {
-                table = table.footer(self.footer.clone());
+                table = table.footer(true);
             }
             if self.min_width_enabled {
                 table = table.min_width(size.width);
@@ -168,7 +142,6 @@ impl App {
 struct Column {
     kind: ColumnKind,
     width: f32,
-    resize_offset: Option<f32>,
 }
 
 impl Column {
@@ -181,11 +154,7 @@ impl Column {
             ColumnKind::Delete => 100.0,
         };
 
-        Self {
-            kind,
-            width,
-            resize_offset: None,
-        }
+        Self { kind, width }
     }
 }
 
@@ -302,7 +271,7 @@ impl<'a> table::Column<'a, Message, Theme, Renderer> for Column {
         self.width
     }
 
-    fn resize_offset(&self) -> Option<f32> {
-        self.resize_offset
+    fn align_y(&self) -> Option<alignment::Vertical> {
+        matches!(self.kind, ColumnKind::Index).then_some(alignment::Vertical::Center)
     }
 }
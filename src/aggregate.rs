@@ -0,0 +1,100 @@
+//! Aggregate the values of rows into a footer, such as in [`Column::footer`].
+//!
+//! The values are extracted from each row and formatted with two decimal places, right
+//! aligned. The footer is empty when there are no rows to aggregate.
+//!
+//! [`Column::footer`]: crate::table::Column::footer
+use iced_core::{alignment, text, Element, Length};
+use iced_widget::Text;
+
+use crate::computed::Aggregate;
+
+/// Creates a footer with the sum of the values of the rows.
+pub fn sum<'a, Row, Message, Theme, Renderer>(
+    rows: &[Row],
+    value: impl Fn(&Row) -> f64,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    aggregated(Aggregate::Sum, rows, value)
+}
+
+/// Creates a footer with the mean of the values of the rows.
+pub fn avg<'a, Row, Message, Theme, Renderer>(
+    rows: &[Row],
+    value: impl Fn(&Row) -> f64,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    aggregated(Aggregate::Mean, rows, value)
+}
+
+/// Creates a footer with the smallest of the values of the rows.
+pub fn min<'a, Row, Message, Theme, Renderer>(
+    rows: &[Row],
+    value: impl Fn(&Row) -> f64,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    aggregated(Aggregate::Min, rows, value)
+}
+
+/// Creates a footer with the largest of the values of the rows.
+pub fn max<'a, Row, Message, Theme, Renderer>(
+    rows: &[Row],
+    value: impl Fn(&Row) -> f64,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    aggregated(Aggregate::Max, rows, value)
+}
+
+/// Creates a footer with the number of rows matching the predicate.
+pub fn count<'a, Row, Message, Theme, Renderer>(
+    rows: &[Row],
+    predicate: impl Fn(&Row) -> bool,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    let count = rows.iter().filter(|row| predicate(row)).count();
+
+    footer(count.to_string())
+}
+
+fn aggregated<'a, Row, Message, Theme, Renderer>(
+    aggregate: Aggregate,
+    rows: &[Row],
+    value: impl Fn(&Row) -> f64,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    let content = aggregate
+        .apply(rows.iter().map(value))
+        .map(|value| format!("{value:.2}"))
+        .unwrap_or_default();
+
+    footer(content)
+}
+
+fn footer<'a, Message, Theme, Renderer>(content: String) -> Element<'a, Message, Theme, Renderer>
+where
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    Text::new(content)
+        .width(Length::Fill)
+        .align_x(alignment::Horizontal::Right)
+        .into()
+}
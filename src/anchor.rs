@@ -0,0 +1,223 @@
+use std::any::Any;
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, Clipboard, Shell};
+use iced_core::{Element, Length, Rectangle, Size, Vector};
+
+/// The position of a keyed row within the content it belongs to.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Position {
+    pub key: u64,
+    pub y: f32,
+    pub height: f32,
+}
+
+/// The positions of the keyed rows of the body, exposed to [`operation`](Find)s.
+#[derive(Debug, Default)]
+pub(crate) struct Positions(pub Vec<Position>);
+
+/// Exposes the positions of the children of the content it wraps, such as the body rows, by
+/// their key, so the body can be kept scrolled to the same rows as they move.
+///
+/// `keys` are the keys of the children of the content, in order. Children without a key
+/// can't be anchored to.
+///
+/// The rows are found through the layout of the content, so it must lay its rows out as its
+/// own children, one for each key, such as a column of rows wrapped in layout transparent
+/// widgets.
+pub(crate) struct Anchor<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    keys: Vec<Option<u64>>,
+}
+
+impl<'a, Message, Theme, Renderer> Anchor<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        keys: Vec<Option<u64>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            keys,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Anchor<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let top = layout.bounds().y;
+
+        debug_assert_eq!(
+            layout.children().count(),
+            self.keys.len(),
+            "the content lays out a child for each key"
+        );
+
+        let mut positions = Positions(
+            layout
+                .children()
+                .zip(&self.keys)
+                .filter_map(|(child, key)| {
+                    let bounds = child.bounds();
+
+                    key.map(|key| Position {
+                        key,
+                        y: bounds.y - top,
+                        height: bounds.height,
+                    })
+                })
+                .collect(),
+        );
+
+        operation.custom(&mut positions, None);
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Anchor<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(anchor: Anchor<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(anchor)
+    }
+}
+
+/// Reads the positions of the outermost keyed rows.
+#[derive(Debug, Default)]
+pub(crate) struct Find(pub Option<Vec<Position>>);
+
+impl Operation for Find {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if self.0.is_none() {
+            operate_on_children(self);
+        }
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&widget::Id>) {
+        if let Some(Positions(positions)) = state.downcast_mut::<Positions>() {
+            self.0.get_or_insert_with(|| std::mem::take(positions));
+        }
+    }
+}
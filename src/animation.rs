@@ -0,0 +1,287 @@
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, window, Clipboard, Shell};
+use iced_core::{Element, Length, Rectangle, Size, Vector};
+
+use crate::divider::Resizing;
+
+#[derive(Debug, Default)]
+struct State {
+    // The width of each column, as last set by the consumer
+    widths: Vec<f32>,
+    // The width each column was last displayed with
+    displayed: Vec<f32>,
+    // Whether `displayed` is kept as the width the columns were dragged to, until the
+    // consumer applies it or the table is redrawn
+    released: bool,
+    animation: Option<Animation>,
+    now: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct Animation {
+    // The offset of each column from its width, as the animation started
+    offsets: Vec<f32>,
+    started: Option<Instant>,
+}
+
+impl Animation {
+    // How far along the animation is, from 0 to 1
+    fn progress(&self, now: Option<Instant>, duration: Duration) -> f32 {
+        match self.started.zip(now) {
+            Some((started, now)) if !duration.is_zero() => {
+                (now.saturating_duration_since(started).as_secs_f32() / duration.as_secs_f32())
+                    .min(1.0)
+            }
+            Some(_) => 1.0,
+            None => 0.0,
+        }
+    }
+}
+
+/// Animates the columns from the width they were displayed with to their new width, when
+/// the consumer changes it, over `duration`.
+///
+/// The offsets of the columns are laid out through the [`Resizing`] of the table, as with
+/// a dragged divider. The width a column was dragged to isn't animated once applied.
+pub(crate) struct Animated<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    widths: Vec<f32>,
+    shown: Vec<usize>,
+    resizing: Resizing,
+    duration: Duration,
+}
+
+impl<'a, Message, Theme, Renderer> Animated<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// `widths` are the widths of the columns, by index, and `shown` the indices of the
+    /// displayed ones, in display order.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        widths: Vec<f32>,
+        shown: Vec<usize>,
+        resizing: Resizing,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            widths,
+            shown,
+            resizing,
+            duration,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Animated<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.widths != self.widths {
+            state.animation = (state.displayed.len() == self.widths.len())
+                .then(|| {
+                    state
+                        .displayed
+                        .iter()
+                        .zip(&self.widths)
+                        .map(|(displayed, width)| displayed - width)
+                        .collect::<Vec<_>>()
+                })
+                .filter(|offsets| offsets.iter().any(|offset| offset.abs() >= 0.5))
+                .map(|offsets| Animation {
+                    offsets,
+                    started: None,
+                });
+
+            state.widths.clone_from(&self.widths);
+            state.released = false;
+        }
+
+        let offsets = match &state.animation {
+            Some(animation) => {
+                // Eases out, slowing down towards the new width
+                let remaining = (1.0 - animation.progress(state.now, self.duration)).powi(3);
+
+                self.shown
+                    .iter()
+                    .map(|&index| animation.offsets[index] * remaining)
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        self.resizing.animate(offsets);
+
+        let node = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        if !state.released {
+            state.displayed.clone_from(&self.widths);
+
+            for (position, &index) in self.shown.iter().enumerate() {
+                state.displayed[index] += self.resizing.offset(position..position + 1);
+            }
+        }
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let event::Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = Some(now);
+            state.released = false;
+
+            if let Some(animation) = &mut state.animation {
+                animation.started.get_or_insert(now);
+
+                if animation.progress(state.now, self.duration) < 1.0 {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                } else {
+                    state.animation = None;
+                }
+
+                shell.invalidate_layout();
+            }
+        }
+
+        let was_dragged = self.resizing.get().is_some();
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if was_dragged && self.resizing.get().is_none() {
+            tree.state.downcast_mut::<State>().released = true;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Animated<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(animated: Animated<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(animated)
+    }
+}
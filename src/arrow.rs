@@ -0,0 +1,154 @@
+//! Display an Apache Arrow [`RecordBatch`] without copying its values into rows.
+//!
+//! The rows of the [`Table`](crate::Table) are the indices of the rows of the batch, as
+//! returned by [`rows`], which can be sorted or filtered like any other rows. Each cell
+//! formats its value from the batch as it is displayed.
+//!
+//! ```no_run
+//! # fn view(batch: arrow_array::RecordBatch) {
+//! let columns = iced_table::arrow::columns(&batch);
+//! let rows = iced_table::arrow::rows(&batch);
+//!
+//! # let _: iced_core::Element<'_, (), iced_widget::Theme, iced_widget::Renderer> =
+//! iced_table::table(&columns, &rows)
+//! # .into();
+//! # }
+//! ```
+use std::fmt;
+
+use arrow_array::{Array, RecordBatch};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use iced_core::{text, Element};
+use iced_widget::Text;
+
+use crate::table;
+
+/// Creates an [`ArrowColumn`] for each column of the batch, titled by the name of its field.
+pub fn columns(batch: &RecordBatch) -> Vec<ArrowColumn> {
+    (0..batch.num_columns())
+        .map(|index| ArrowColumn::new(batch, index))
+        .collect()
+}
+
+/// Returns the indices of the rows of the batch, the rows of a [`Table`](crate::Table)
+/// displaying it.
+pub fn rows(batch: &RecordBatch) -> Vec<usize> {
+    (0..batch.num_rows()).collect()
+}
+
+/// A [`Column`](table::Column) displaying a column of a [`RecordBatch`].
+///
+/// Its cells are [plain](table::Column::is_plain) text, with null values left empty.
+#[derive(Clone)]
+pub struct ArrowColumn {
+    batch: RecordBatch,
+    index: usize,
+    title: String,
+    width: f32,
+    default_width: f32,
+}
+
+impl ArrowColumn {
+    /// Creates a new [`ArrowColumn`] displaying the column of the batch at `index`.
+    ///
+    /// The batch is shared with the column, not copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the batch has no column at `index`.
+    pub fn new(batch: &RecordBatch, index: usize) -> Self {
+        let title = batch.schema_ref().field(index).name().clone();
+
+        Self {
+            batch: batch.clone(),
+            index,
+            title,
+            width: 100.0,
+            default_width: 100.0,
+        }
+    }
+
+    /// Sets the title of the [`ArrowColumn`], which defaults to the name of its field.
+    pub fn title(self, title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..self
+        }
+    }
+
+    /// Sets the width of the [`ArrowColumn`], which it is also reset to with the divider,
+    /// see [`on_column_reset`](crate::Table::on_column_reset).
+    pub fn width(self, width: f32) -> Self {
+        Self {
+            width,
+            default_width: width,
+            ..self
+        }
+    }
+
+    /// Sets the width of a resized [`ArrowColumn`], as emitted by
+    /// [`on_column_resize`](crate::Table::on_column_resize).
+    pub fn set_width(&mut self, width: f32) {
+        self.width = width;
+    }
+
+    /// Returns the value of the row of the batch, formatted as it is displayed.
+    pub fn value(&self, row: usize) -> String {
+        let array = self.batch.column(self.index);
+
+        if array.is_null(row) {
+            return String::new();
+        }
+
+        ArrayFormatter::try_new(array.as_ref(), &FormatOptions::default())
+            .map(|formatter| formatter.value(row).to_string())
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for ArrowColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrowColumn")
+            .field("index", &self.index)
+            .field("title", &self.title)
+            .field("width", &self.width)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> table::Column<'a, Message, Theme, Renderer> for ArrowColumn
+where
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    type Row = usize;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        Text::new(self.title.as_str()).into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        _row_index: usize,
+        row: &'a Self::Row,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Text::new(self.value(*row)).into()
+    }
+
+    fn cell_text(&self, _col_index: usize, _row_index: usize, row: &Self::Row) -> Option<String> {
+        Some(self.value(*row))
+    }
+
+    fn is_plain(&self) -> bool {
+        true
+    }
+
+    fn default_width(&self) -> Option<f32> {
+        Some(self.default_width)
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+}
@@ -0,0 +1,312 @@
+//! Edit a cell by typing, picking from the suggestions matching the input.
+use iced_core::keyboard::{self, key};
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, text, Clipboard, Shell};
+use iced_core::{Element, Length, Rectangle, Size, Vector};
+use iced_widget::overlay::menu;
+use iced_widget::text_input::{self, TextInput};
+
+/// Creates an [`Autocomplete`] editor with the given `value`, showing the `suggestions`
+/// whose text contains the value, ignoring case, in a dropdown while it is focused.
+///
+/// Typing produces `on_input`. Picking a suggestion, by clicking it or by highlighting it
+/// with the arrow keys & pressing Enter, produces `on_select`.
+///
+/// It is meant to be returned by [`Column::edit_cell`](crate::table::Column::edit_cell).
+/// Enter then also commits the edit with [`Table::on_cell_edit`](crate::Table::on_cell_edit),
+/// right after `on_select`, so the picked suggestion can be stored before being applied.
+///
+/// The suggestions can come from a fixed list, or from an async source queried with the
+/// input reported by `on_input`, and passed back once fetched.
+pub fn autocomplete<'a, T, Message, Theme, Renderer>(
+    value: &str,
+    suggestions: &[T],
+    on_input: impl Fn(String) -> Message + 'a,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> Autocomplete<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Message: Clone,
+    Theme: text_input::Catalog + menu::Catalog,
+    Renderer: text::Renderer,
+{
+    Autocomplete::new(value, suggestions, on_input, on_select)
+}
+
+#[derive(Debug)]
+struct State {
+    menu: menu::State,
+    hovered: Option<usize>,
+}
+
+/// A text input suggesting values in a dropdown, see [`autocomplete`].
+#[allow(missing_debug_implementations)]
+pub struct Autocomplete<'a, T, Message, Theme, Renderer>
+where
+    Theme: text_input::Catalog + menu::Catalog,
+    Renderer: text::Renderer,
+{
+    input: TextInput<'a, Message, Theme, Renderer>,
+    suggestions: Vec<T>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Autocomplete<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Message: Clone,
+    Theme: text_input::Catalog + menu::Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Autocomplete`], see [`autocomplete`].
+    pub fn new(
+        value: &str,
+        suggestions: &[T],
+        on_input: impl Fn(String) -> Message + 'a,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        let query = value.to_lowercase();
+
+        Self {
+            input: TextInput::new("", value)
+                .on_input(on_input)
+                .width(Length::Fill),
+            suggestions: suggestions
+                .iter()
+                .filter(|suggestion| suggestion.to_string().to_lowercase().contains(&query))
+                .cloned()
+                .collect(),
+            on_select: Box::new(on_select),
+            menu_class: <Theme as menu::Catalog>::default(),
+        }
+    }
+
+    /// Sets the [`Id`](text_input::Id) of the input, such as to focus it once the edit
+    /// starts.
+    pub fn id(self, id: impl Into<text_input::Id>) -> Self {
+        Self {
+            input: self.input.id(id),
+            ..self
+        }
+    }
+
+    /// Sets the style class of the dropdown.
+    pub fn menu_class(self, menu_class: impl Into<<Theme as menu::Catalog>::Class<'a>>) -> Self {
+        Self {
+            menu_class: menu_class.into(),
+            ..self
+        }
+    }
+
+    fn is_focused(&self, tree: &Tree) -> bool {
+        tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused()
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Autocomplete<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Message: Clone,
+    Theme: text_input::Catalog + menu::Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            menu: menu::State::new(),
+            hovered: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.input as &dyn Widget<Message, Theme, Renderer>,
+        )]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.input as &dyn Widget<Message, Theme, Renderer>]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<Message, Theme, Renderer>::size(&self.input)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = self
+            .input
+            .layout(&mut tree.children[0], renderer, limits, None);
+
+        layout::Node::with_children(node.size(), vec![node])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        Widget::<Message, Theme, Renderer>::operate(
+            &self.input,
+            &mut tree.children[0],
+            layout.children().next().expect("input has a layout"),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            ..
+        }) = &event
+        {
+            let count = self.suggestions.len();
+
+            if self.is_focused(tree) && count > 0 {
+                let state = tree.state.downcast_mut::<State>();
+                let hovered = state.hovered.filter(|hovered| *hovered < count);
+
+                match key {
+                    key::Named::ArrowDown => {
+                        state.hovered = Some(hovered.map_or(0, |hovered| (hovered + 1) % count));
+
+                        return event::Status::Captured;
+                    }
+                    key::Named::ArrowUp => {
+                        state.hovered = Some(
+                            hovered.map_or(count - 1, |hovered| (hovered + count - 1) % count),
+                        );
+
+                        return event::Status::Captured;
+                    }
+                    // Not captured, so the edit is committed right after
+                    key::Named::Enter => {
+                        if let Some(hovered) = hovered {
+                            shell.publish((self.on_select)(self.suggestions[hovered].clone()));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Widget::<Message, Theme, Renderer>::on_event(
+            &mut self.input,
+            &mut tree.children[0],
+            event,
+            layout.children().next().expect("input has a layout"),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::<Message, Theme, Renderer>::mouse_interaction(
+            &self.input,
+            &tree.children[0],
+            layout.children().next().expect("input has a layout"),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        Widget::<Message, Theme, Renderer>::draw(
+            &self.input,
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().expect("input has a layout"),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if self.suggestions.is_empty() || !self.is_focused(tree) {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        let menu = menu::Menu::new(
+            &mut state.menu,
+            &self.suggestions,
+            &mut state.hovered,
+            &self.on_select,
+            None,
+            &self.menu_class,
+        )
+        .width(bounds.width);
+
+        Some(menu.overlay(layout.position() + translation, bounds.height))
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Autocomplete<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: ToString + Clone + 'a,
+    Message: Clone + 'a,
+    Theme: text_input::Catalog + menu::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(autocomplete: Autocomplete<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(autocomplete)
+    }
+}
@@ -0,0 +1,724 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::operation::scrollable::AbsoluteOffset;
+use iced_core::widget::operation::{self, Operation};
+use iced_core::widget::{self, Tree, Widget};
+use iced_core::{event, keyboard, overlay, renderer, window, Clipboard, Shell};
+use iced_core::{Alignment, Element, Length, Padding, Point, Rectangle, Size, Vector};
+
+use crate::anchor;
+use crate::divider::{Resize, Resizing};
+use crate::lazy::Visible;
+use crate::operation::ScrollOffset;
+
+/// The state of the bands, exposed to [`operation`](crate::operation)s right after the
+/// state of the table they belong to.
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    resizing: Option<Resize>,
+    is_near_end: bool,
+    // Whether following the end of the body is paused, after scrolling up
+    is_paused: bool,
+    offset_y: Option<f32>,
+    // The vertical offset & height of the body, as of the last layout or event
+    visible: Option<(f32, f32)>,
+    modifiers: keyboard::Modifiers,
+    // The key of the row at the top of the body, with the distance from its top to the top
+    // of the viewport
+    anchor: Option<(u64, f32)>,
+    // How the body is scrolled, as of the last scroll or layout
+    scrolled: Option<Scroll>,
+    // Whether the bands were laid out again since the body was last synced
+    is_laid_out: bool,
+    /// How the body is scrolled, as of the operation.
+    pub scroll: Option<ScrollOffset>,
+    /// The offset the body should be scrolled to, once the operation is done.
+    pub scroll_to: Option<AbsoluteOffset>,
+}
+
+/// Where the table was last right-clicked, relative to its top-left corner.
+///
+/// It is set by the bands before passing the click on, for the headers to report it
+/// regardless of the scrollable they are in.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RightPress(Rc<Cell<Point>>);
+
+impl RightPress {
+    pub fn get(&self) -> Point {
+        self.0.get()
+    }
+}
+
+/// How the body is scrolled.
+#[derive(Debug, Clone, Copy)]
+struct Scroll {
+    offset: Vector,
+    content: Size,
+    bounds: Size,
+    // The height of the content left below the bounds
+    remaining: f32,
+}
+
+/// Stacks the scrollables of a table around its body: the header & pinned rows above it, the
+/// pinned rows & footer below it, scrolling them horizontally along with the body.
+///
+/// It also keeps the on-going [`Resizing`] across views, until the resize is finished, and
+/// the [`Visible`] part of a virtualized body.
+pub(crate) struct Bands<'a, Message, Theme, Renderer> {
+    // The bands above the body, the body & the bands below it
+    bands: Vec<Element<'a, Message, Theme, Renderer>>,
+    // The index of the body among the bands
+    body: usize,
+    resizing: Resizing,
+    visible: Option<Visible>,
+    right_press: Option<RightPress>,
+    // The distance from the bottom of the body within which the message is produced
+    on_scroll_near_end: Option<(f32, Message)>,
+    follow: bool,
+    scroll_overflow_only: bool,
+    anchor: bool,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message, Theme, Renderer> Bands<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        above: impl IntoIterator<Item = impl Into<Element<'a, Message, Theme, Renderer>>>,
+        body: impl Into<Element<'a, Message, Theme, Renderer>>,
+        below: impl IntoIterator<Item = impl Into<Element<'a, Message, Theme, Renderer>>>,
+        resizing: Resizing,
+    ) -> Self {
+        let mut bands: Vec<_> = above.into_iter().map(Into::into).collect();
+        let body_index = bands.len();
+
+        bands.push(body.into());
+        bands.extend(below.into_iter().map(Into::into));
+
+        Self {
+            bands,
+            body: body_index,
+            resizing,
+            visible: None,
+            right_press: None,
+            on_scroll_near_end: None,
+            follow: false,
+            scroll_overflow_only: false,
+            anchor: false,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    pub fn width(self, width: Length) -> Self {
+        Self { width, ..self }
+    }
+
+    pub fn height(self, height: Length) -> Self {
+        Self { height, ..self }
+    }
+
+    /// Sets whether the body is kept scrolled to its end as rows are appended, until it is
+    /// scrolled up.
+    pub fn follow(self, follow: bool) -> Self {
+        Self { follow, ..self }
+    }
+
+    /// Sets whether the body is only scrolled along the axes its content overflows, leaving
+    /// the rest of the mouse wheel to the parent.
+    pub fn scroll_overflow_only(self, scroll_overflow_only: bool) -> Self {
+        Self {
+            scroll_overflow_only,
+            ..self
+        }
+    }
+
+    /// Sets whether the body is kept scrolled to the same keyed rows, as rows are added or
+    /// removed above them.
+    pub fn anchor(self, anchor: bool) -> Self {
+        Self { anchor, ..self }
+    }
+
+    /// Sets where the visible part of the body is shared with its lazy rows, which are laid
+    /// out again as the body is scrolled or resized.
+    pub fn visible(self, visible: Option<Visible>) -> Self {
+        Self { visible, ..self }
+    }
+
+    /// Sets where the position of a right click within the table is shared with the headers.
+    pub fn right_press(self, right_press: Option<RightPress>) -> Self {
+        Self {
+            right_press,
+            ..self
+        }
+    }
+
+    /// Sets the message produced once the body is scrolled within `threshold` of its bottom.
+    pub fn on_scroll_near_end(self, on_scroll_near_end: Option<(f32, Message)>) -> Self {
+        Self {
+            on_scroll_near_end,
+            ..self
+        }
+    }
+
+    fn body_scroll(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Scroll> {
+        let mut scrolled = Scrolled(None);
+
+        self.bands[self.body].as_widget().operate(
+            &mut tree.children[self.body],
+            layout.children().nth(self.body).expect("body has a layout"),
+            renderer,
+            &mut scrolled,
+        );
+
+        scrolled.0
+    }
+
+    fn scroll_body_to(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        offset: AbsoluteOffset,
+    ) {
+        self.bands[self.body].as_widget().operate(
+            &mut tree.children[self.body],
+            layout.children().nth(self.body).expect("body has a layout"),
+            renderer,
+            &mut ScrollTo(Some(offset)),
+        );
+    }
+
+    // Scrolls the other bands to the horizontal offset of the body, returning how the body
+    // is scrolled
+    fn sync(&self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer) -> Option<Scroll> {
+        let layouts: Vec<_> = layout.children().collect();
+
+        let scroll = self.body_scroll(tree, layout, renderer)?;
+
+        for (index, band) in self.bands.iter().enumerate() {
+            if index != self.body {
+                band.as_widget().operate(
+                    &mut tree.children[index],
+                    layouts[index],
+                    renderer,
+                    &mut ScrollTo(Some(AbsoluteOffset {
+                        x: scroll.offset.x,
+                        y: 0.0,
+                    })),
+                );
+            }
+        }
+
+        Some(scroll)
+    }
+
+    // Anchors the body to the keyed row at its top, first scrolling it back to the previous
+    // anchor if `restore` is set, such as once the rows are laid out again, returning whether
+    // it was scrolled
+    fn anchor_rows(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        restore: bool,
+    ) -> bool {
+        let Some(scroll) = self.body_scroll(tree, layout, renderer) else {
+            return false;
+        };
+
+        let mut find = anchor::Find::default();
+
+        self.bands[self.body].as_widget().operate(
+            &mut tree.children[self.body],
+            layout.children().nth(self.body).expect("body has a layout"),
+            renderer,
+            &mut find,
+        );
+
+        let Some(positions) = find.0 else {
+            return false;
+        };
+
+        let mut offset = scroll.offset;
+        let previous = tree.state.downcast_ref::<State>().anchor;
+
+        let restored = previous.filter(|_| restore).and_then(|(key, distance)| {
+            positions
+                .iter()
+                .find(|position| position.key == key)
+                .map(|position| (position.y - distance).max(0.0))
+        });
+
+        let restored = restored.filter(|y| (y - offset.y).abs() > 0.5);
+
+        if let Some(y) = restored {
+            offset.y = y;
+
+            self.scroll_body_to(tree, layout, renderer, AbsoluteOffset { x: offset.x, y });
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        // Not scrolled up by the user, when following the end of the body
+        if restore && state.offset_y.is_some() {
+            state.offset_y = Some(offset.y);
+        }
+
+        state.anchor = positions
+            .iter()
+            .find(|position| position.y + position.height > offset.y)
+            .map(|position| (position.key, position.y - offset.y));
+
+        restored.is_some()
+    }
+
+    // Drops the movement of the wheel along the axes the content of the body fits in
+    fn overflowing_delta(
+        &self,
+        tree: &Tree,
+        delta: mouse::ScrollDelta,
+    ) -> Option<mouse::ScrollDelta> {
+        let Some(scroll) = tree.state.downcast_ref::<State>().scrolled else {
+            return Some(delta);
+        };
+
+        let fits_x = scroll.content.width <= scroll.bounds.width;
+        let fits_y = scroll.content.height <= scroll.bounds.height;
+
+        let drop = |x: f32, y: f32, is_swapped: bool| {
+            let (fits_x, fits_y) = if is_swapped {
+                (fits_y, fits_x)
+            } else {
+                (fits_x, fits_y)
+            };
+
+            let x = if fits_x { 0.0 } else { x };
+            let y = if fits_y { 0.0 } else { y };
+
+            (x != 0.0 || y != 0.0).then_some((x, y))
+        };
+
+        match delta {
+            mouse::ScrollDelta::Lines { x, y } => {
+                // Lines scroll horizontally while shift is pressed, unless already
+                // inverted by macOS
+                let is_swapped = tree.state.downcast_ref::<State>().modifiers.shift()
+                    && !cfg!(target_os = "macos");
+
+                drop(x, y, is_swapped).map(|(x, y)| mouse::ScrollDelta::Lines { x, y })
+            }
+            mouse::ScrollDelta::Pixels { x, y } => {
+                drop(x, y, false).map(|(x, y)| mouse::ScrollDelta::Pixels { x, y })
+            }
+        }
+    }
+
+    // Shares the visible part of the body, returning whether it changed since last shared
+    fn share_visible(&self, tree: &mut Tree, scroll: Option<Scroll>) -> bool {
+        let Some(visible) = &self.visible else {
+            return false;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let current = scroll.map(|scroll| (scroll.offset.y, scroll.bounds.height));
+
+        visible.set(current);
+
+        std::mem::replace(&mut state.visible, current) != current
+    }
+
+    // Keeps the body scrolled to its end, unless it was scrolled up
+    fn follow_end(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        scroll: &mut Scroll,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let is_at_end = scroll.remaining <= 0.5;
+
+        if state
+            .offset_y
+            .is_some_and(|offset_y| scroll.offset.y < offset_y)
+        {
+            state.is_paused = true;
+        }
+
+        if is_at_end {
+            state.is_paused = false;
+        }
+
+        if !state.is_paused && !is_at_end {
+            scroll.offset.y += scroll.remaining;
+            scroll.remaining = 0.0;
+
+            self.scroll_body_to(
+                tree,
+                layout,
+                renderer,
+                AbsoluteOffset {
+                    x: scroll.offset.x,
+                    y: scroll.offset.y,
+                },
+            );
+        }
+
+        tree.state.downcast_mut::<State>().offset_y = Some(scroll.offset.y);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Bands<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Message: Clone,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.bands.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_ref::<State>();
+
+        self.resizing.set(state.resizing);
+
+        if let Some(visible) = &self.visible {
+            visible.set(state.visible);
+        }
+
+        tree.diff_children(&self.bands);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let resolve = |tree: &mut Tree| {
+            layout::flex::resolve(
+                layout::flex::Axis::Vertical,
+                renderer,
+                limits,
+                self.width,
+                self.height,
+                Padding::ZERO,
+                0.0,
+                Alignment::Start,
+                &self.bands,
+                &mut tree.children,
+            )
+        };
+
+        let mut node = resolve(tree);
+
+        // Once more with the rows coming into view, such as on the first layout
+        if self.visible.is_some() {
+            let scroll = self.body_scroll(tree, Layout::new(&node), renderer);
+
+            if self.share_visible(tree, scroll) {
+                node = resolve(tree);
+            }
+        }
+
+        // The body is scrolled back to its anchor on the next event, not while laid out
+        tree.state.downcast_mut::<State>().is_laid_out = true;
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let scroll = self.body_scroll(tree, layout, renderer);
+        let state = tree.state.downcast_mut::<State>();
+
+        state.scroll = scroll.map(|scroll| ScrollOffset {
+            offset: AbsoluteOffset {
+                x: scroll.offset.x,
+                y: scroll.offset.y,
+            },
+            content_size: scroll.content,
+        });
+
+        operation.custom(state, None);
+
+        if let Some(offset) = tree.state.downcast_mut::<State>().scroll_to.take() {
+            self.scroll_body_to(tree, layout, renderer, offset);
+
+            let scroll = self.sync(tree, layout, renderer);
+            tree.state.downcast_mut::<State>().scrolled = scroll;
+
+            if self.anchor {
+                self.anchor_rows(tree, layout, renderer, false);
+            }
+        }
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((band, tree), layout) in self
+                .bands
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                band.as_widget().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            tree.state.downcast_mut::<State>().modifiers = modifiers;
+        }
+
+        if let (
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)),
+            Some(right_press),
+            Some(position),
+        ) = (
+            &event,
+            &self.right_press,
+            cursor.position_in(layout.bounds()),
+        ) {
+            right_press.0.set(position);
+        }
+
+        // The rows may have moved since laid out again, before the event reaches them
+        let is_laid_out = std::mem::take(&mut tree.state.downcast_mut::<State>().is_laid_out);
+
+        if is_laid_out && self.anchor && self.anchor_rows(tree, layout, renderer, true) {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        let is_scroll = matches!(
+            event,
+            event::Event::Mouse(mouse::Event::WheelScrolled { .. }) | event::Event::Touch(_)
+        );
+
+        let event = match event {
+            event::Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if self.scroll_overflow_only =>
+            {
+                match self.overflowing_delta(tree, delta) {
+                    Some(delta) => event::Event::Mouse(mouse::Event::WheelScrolled { delta }),
+                    None => return event::Status::Ignored,
+                }
+            }
+            event => event,
+        };
+
+        let status = self
+            .bands
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((band, tree), layout)| {
+                band.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        tree.state.downcast_mut::<State>().resizing = self.resizing.get();
+
+        // The body only moves as it is scrolled, such as by the wheel or its scrollbars, or
+        // as it is laid out again
+        if !is_scroll && !is_laid_out && status == event::Status::Ignored {
+            return status;
+        }
+
+        let mut scroll = self.sync(tree, layout, renderer);
+
+        if let Some(scroll) = scroll.as_mut().filter(|_| self.follow) {
+            self.follow_end(tree, layout, renderer, scroll);
+        }
+
+        if self.anchor {
+            self.anchor_rows(tree, layout, renderer, false);
+        }
+
+        tree.state.downcast_mut::<State>().scrolled = scroll;
+
+        if self.share_visible(tree, scroll) {
+            shell.invalidate_layout();
+        }
+
+        if let Some(((threshold, message), scroll)) = self.on_scroll_near_end.as_ref().zip(scroll) {
+            let state = tree.state.downcast_mut::<State>();
+            let is_near_end = scroll.remaining <= *threshold;
+
+            // Only once until scrolled away, or until more rows are appended
+            if is_near_end && !state.is_near_end {
+                shell.publish(message.clone());
+            }
+
+            state.is_near_end = is_near_end;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.bands
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((band, tree), layout)| {
+                band.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((band, tree), layout) in self.bands.iter().zip(&tree.children).zip(layout.children()) {
+            band.as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.bands, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Bands<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(bands: Bands<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(bands)
+    }
+}
+
+/// Reads how the outermost scrollable is scrolled.
+struct Scrolled(Option<Scroll>);
+
+impl Operation for Scrolled {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if self.0.is_none() {
+            operate_on_children(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.0.get_or_insert(Scroll {
+            offset: translation,
+            content: content_bounds.size(),
+            bounds: bounds.size(),
+            remaining: content_bounds.height - bounds.height - translation.y,
+        });
+    }
+}
+
+/// Scrolls the outermost scrollable to the given offset.
+struct ScrollTo(Option<AbsoluteOffset>);
+
+impl Operation for ScrollTo {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if self.0.is_some() {
+            operate_on_children(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        if let Some(offset) = self.0.take() {
+            state.scroll_to(offset);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, Clipboard, Shell};
+use iced_core::{Element, Length, Rectangle, Size, Vector};
+
+use crate::divider::{Resize, Resizing};
+
+#[derive(Debug, Default)]
+struct State {
+    key: Option<u64>,
+    shape: u64,
+    limits: Option<layout::Limits>,
+    resizing: Option<Resize>,
+    animation: Vec<f32>,
+    node: layout::Node,
+}
+
+/// Reuses the layout of its content for as long as `key`, the layout limits, the
+/// [`Resizing`] and the shape of the widget tree of the content stay the same. A `None` key
+/// disables the cache.
+///
+/// `key` must hash everything the layout of the content depends on besides its shape, such
+/// as the widths of the columns and the generation of the content provided by the consumer.
+pub(crate) struct Cached<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    key: Option<u64>,
+    resizing: Resizing,
+}
+
+impl<'a, Message, Theme, Renderer> Cached<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        key: Option<u64>,
+        resizing: Resizing,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            key,
+            resizing,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Cached<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let shape = shape(&tree.children[0]);
+        let state = tree.state.downcast_mut::<State>();
+        let resizing = self.resizing.get();
+        let animation = self.resizing.animation();
+
+        if self.key.is_none()
+            || state.key != self.key
+            || state.shape != shape
+            || state.limits != Some(*limits)
+            || state.resizing != resizing
+            || state.animation != animation
+        {
+            state.node = self
+                .content
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, limits);
+            state.key = self.key;
+            state.shape = shape;
+            state.limits = Some(*limits);
+            state.resizing = resizing;
+            state.animation = animation;
+        }
+
+        state.node.clone()
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Cached<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(cached: Cached<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(cached)
+    }
+}
+
+// The hash of the tags of a widget tree & of the number of children of each of its nodes,
+// which changes along with the widgets of the content, such as dividers set or removed
+fn shape(tree: &Tree) -> u64 {
+    fn hash(tree: &Tree, hasher: &mut DefaultHasher) {
+        tree.tag.hash(hasher);
+        tree.children.len().hash(hasher);
+
+        for child in &tree.children {
+            hash(child, hasher);
+        }
+    }
+
+    let mut hasher = DefaultHasher::new();
+    hash(tree, &mut hasher);
+
+    hasher.finish()
+}
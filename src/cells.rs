@@ -0,0 +1,549 @@
+use std::ops::Range;
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{tree, Operation, Tree, Widget};
+use iced_core::{alignment, text};
+use iced_core::{event, overlay, renderer, Clipboard, Shell};
+use iced_core::{Border, Color, Element, Length, Pixels, Rectangle, Size, Vector};
+
+use crate::divider::Resizing;
+use crate::focus;
+
+const DEBUG_COLOR: Color = Color::from_rgb(1.0, 0.0, 0.0);
+const DEBUG_SPACER_COLOR: Color = Color::from_rgba(1.0, 0.0, 1.0, 0.25);
+
+/// What [`Cells`] draws over its content to diagnose the layout of the table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum DebugMode {
+    #[default]
+    Off,
+    /// Outline each cell and highlight the `min_width` spacer.
+    Bounds,
+    /// Same as [`DebugMode::Bounds`], also labeling each cell with its column index.
+    Labeled,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    // The horizontal scroll offset of the enclosing scrollable, as of the last event
+    shift: f32,
+}
+
+/// Lays out the cells of a single row (or the header / footer) side by side, aligning
+/// each cell vertically within the height of the tallest one.
+///
+/// The first `frozen` cells stay in place when the enclosing scrollable is scrolled
+/// horizontally, covering the cells scrolled under them.
+///
+/// The cells spanning the column being [`Resizing`] are widened by the offset of the
+/// resize, narrowing the spacer accordingly.
+pub(crate) struct Cells<'a, Message, Theme, Renderer> {
+    cells: Vec<Element<'a, Message, Theme, Renderer>>,
+    alignments: Vec<alignment::Vertical>,
+    has_spacer: bool,
+    height: Option<f32>,
+    frozen: usize,
+    resizing: Option<Resizing>,
+    spans: Vec<Range<usize>>,
+    keys: Option<(usize, Vec<usize>)>,
+    debug: DebugMode,
+}
+
+impl<'a, Message, Theme, Renderer> Cells<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        cells: impl IntoIterator<Item = (Element<'a, Message, Theme, Renderer>, alignment::Vertical)>,
+    ) -> Self {
+        let (cells, alignments) = cells.into_iter().unzip();
+
+        Self {
+            cells,
+            alignments,
+            has_spacer: false,
+            height: None,
+            frozen: 0,
+            resizing: None,
+            spans: Vec::new(),
+            keys: None,
+            debug: DebugMode::Off,
+        }
+    }
+
+    /// Pushes the spacer enforcing the `min_width` of the table, if any.
+    pub fn push_maybe(mut self, spacer: Option<Element<'a, Message, Theme, Renderer>>) -> Self {
+        if let Some(spacer) = spacer {
+            self.cells.push(spacer);
+            self.alignments.push(alignment::Vertical::Top);
+            self.has_spacer = true;
+        }
+        self
+    }
+
+    /// Sets a fixed height, instead of the height of the tallest cell.
+    pub fn height(self, height: Option<f32>) -> Self {
+        Self { height, ..self }
+    }
+
+    /// Sets how many leading cells stay in place when scrolled horizontally.
+    pub fn frozen(self, frozen: usize) -> Self {
+        Self { frozen, ..self }
+    }
+
+    /// Follows the given [`Resizing`], where each cell spans a single displayed column
+    /// unless [`spans`](Self::spans) are set.
+    pub fn resizing(self, resizing: Resizing) -> Self {
+        Self {
+            resizing: Some(resizing),
+            ..self
+        }
+    }
+
+    /// Sets the positions of the displayed columns spanned by each cell.
+    pub fn spans(self, spans: Vec<Range<usize>>) -> Self {
+        Self { spans, ..self }
+    }
+
+    /// Sets the index of the row of the body & the index of the column of each cell,
+    /// published to the operations focusing a cell.
+    pub fn keys(self, row: usize, columns: Vec<usize>) -> Self {
+        Self {
+            keys: Some((row, columns)),
+            ..self
+        }
+    }
+
+    // The offset of the resize for the cell at `index`
+    fn resize_offset(&self, index: usize) -> f32 {
+        let Some(resizing) = &self.resizing else {
+            return 0.0;
+        };
+
+        if self.has_spacer && index + 1 == self.cells.len() {
+            return -resizing.total_offset();
+        }
+
+        match self.spans.get(index) {
+            Some(span) => resizing.offset(span.clone()),
+            None => resizing.offset(index..index + 1),
+        }
+    }
+
+    pub fn debug(self, debug: DebugMode) -> Self {
+        Self { debug, ..self }
+    }
+
+    // The horizontal scroll offset is the offset of the visible part of the content
+    fn placement(&self, layout: Layout<'_>, viewport: &Rectangle) -> Placement {
+        let shift = if self.frozen == 0 {
+            0.0
+        } else {
+            (viewport.x - layout.bounds().x).max(0.0)
+        };
+
+        // Nothing is covered until scrolled
+        let frozen_right = if shift > 0.0 {
+            layout
+                .children()
+                .take(self.frozen)
+                .last()
+                .map_or(layout.bounds().x, |cell| {
+                    cell.bounds().x + cell.bounds().width
+                })
+                + shift
+        } else {
+            layout.bounds().x
+        };
+
+        Placement {
+            shift,
+            frozen: self.frozen,
+            frozen_right,
+        }
+    }
+}
+
+/// Where the cell at `index` is drawn, given the shift of the `frozen` cells.
+#[derive(Debug, Clone, Copy)]
+struct Placement {
+    shift: f32,
+    frozen: usize,
+    frozen_right: f32,
+}
+
+impl Placement {
+    fn is_frozen(&self, index: usize) -> bool {
+        index < self.frozen && self.shift > 0.0
+    }
+
+    // The cursor as seen by the cell at `index`
+    fn cursor(&self, index: usize, cursor: Cursor) -> Cursor {
+        if self.shift == 0.0 {
+            return cursor;
+        }
+
+        match cursor.position() {
+            Some(position) if index < self.frozen => {
+                Cursor::Available(position - Vector::new(self.shift, 0.0))
+            }
+            // The frozen cells cover the cells scrolled under them
+            Some(position) if position.x < self.frozen_right => Cursor::Unavailable,
+            _ => cursor,
+        }
+    }
+
+    // The visible part of the content, as seen by the cell at `index`
+    fn viewport(&self, index: usize, viewport: &Rectangle) -> Rectangle {
+        if self.is_frozen(index) {
+            *viewport - Vector::new(self.shift, 0.0)
+        } else {
+            *viewport
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Cells<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.cells.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.cells);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let max_height = self.height.unwrap_or(limits.max().height);
+        let cell_limits = layout::Limits::new(Size::ZERO, Size::new(f32::INFINITY, max_height));
+
+        let mut x = 0.0;
+        let mut height: f32 = 0.0;
+
+        let mut nodes: Vec<_> = self
+            .cells
+            .iter()
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(index, (cell, tree))| {
+                let mut node = cell.as_widget().layout(tree, renderer, &cell_limits);
+
+                let offset = self.resize_offset(index);
+
+                if offset != 0.0 {
+                    let width = (node.size().width + offset).max(0.0);
+                    let limits =
+                        layout::Limits::new(Size::new(width, 0.0), Size::new(width, max_height));
+
+                    node = cell.as_widget().layout(tree, renderer, &limits);
+                }
+
+                let node = node.move_to((x, 0.0));
+
+                x += node.size().width;
+                height = height.max(node.size().height);
+
+                node
+            })
+            .collect();
+
+        let height = self.height.unwrap_or(height);
+
+        for (node, align_y) in nodes.iter_mut().zip(&self.alignments) {
+            let offset = match align_y {
+                alignment::Vertical::Top => 0.0,
+                alignment::Vertical::Center => (height - node.size().height) / 2.0,
+                alignment::Vertical::Bottom => height - node.size().height,
+            };
+
+            node.translate_mut(Vector::new(0.0, offset));
+        }
+
+        let size = limits.resolve(Length::Shrink, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.cells
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .enumerate()
+                .for_each(|(index, ((cell, tree), layout))| {
+                    if let Some((row, columns)) = &self.keys {
+                        let column = columns.get(index).map(|column| (*row, *column));
+
+                        operation.custom(&mut focus::Cell(column), None);
+                    }
+
+                    cell.as_widget().operate(tree, layout, renderer, operation);
+                });
+
+            if self.keys.is_some() {
+                operation.custom(&mut focus::Cell(None), None);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let placement = self.placement(layout, viewport);
+
+        tree.state.downcast_mut::<State>().shift = placement.shift;
+
+        self.cells
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .map(|(index, ((cell, tree), layout))| {
+                let cursor = placement.cursor(index, cursor);
+                let viewport = placement.viewport(index, viewport);
+
+                cell.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let placement = self.placement(layout, viewport);
+
+        self.cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .map(|(index, ((cell, tree), layout))| {
+                cell.as_widget().mouse_interaction(
+                    tree,
+                    layout,
+                    placement.cursor(index, cursor),
+                    &placement.viewport(index, viewport),
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let placement = self.placement(layout, viewport);
+
+        let cells = || self.cells.iter().zip(&tree.children).zip(layout.children());
+
+        for (index, ((cell, tree), layout)) in cells().enumerate() {
+            let bounds = layout.bounds();
+
+            if placement.is_frozen(index) || !bounds.intersects(viewport) {
+                continue;
+            }
+
+            let cursor = placement.cursor(index, cursor);
+
+            if bounds.x >= placement.frozen_right {
+                cell.as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
+            } else if bounds.x + bounds.width > placement.frozen_right {
+                // Clip the part of the cell covered by the frozen cells
+                let visible = Rectangle {
+                    x: placement.frozen_right,
+                    width: viewport.x + viewport.width - placement.frozen_right,
+                    ..*viewport
+                };
+
+                renderer.with_layer(visible, |renderer| {
+                    cell.as_widget()
+                        .draw(tree, renderer, theme, style, layout, cursor, &visible);
+                });
+            }
+        }
+
+        for (index, ((cell, tree), layout)) in cells().enumerate().take(self.frozen) {
+            if !placement.is_frozen(index) {
+                break;
+            }
+
+            let viewport = placement.viewport(index, viewport);
+
+            renderer.with_translation(Vector::new(placement.shift, 0.0), |renderer| {
+                cell.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    placement.cursor(index, cursor),
+                    &viewport,
+                );
+            });
+        }
+
+        if self.debug != DebugMode::Off {
+            renderer.with_layer(*viewport, |renderer| {
+                self.draw_debug(renderer, layout, viewport);
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let shift = tree.state.downcast_ref::<State>().shift;
+        let frozen = self.frozen;
+
+        let children = self
+            .cells
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .filter_map(|(index, ((cell, tree), layout))| {
+                let translation = if index < frozen {
+                    translation + Vector::new(shift, 0.0)
+                } else {
+                    translation
+                };
+
+                cell.as_widget_mut()
+                    .overlay(tree, layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Cells<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn draw_debug(&self, renderer: &mut Renderer, layout: Layout<'_>, viewport: &Rectangle) {
+        let column_count = self.cells.len() - usize::from(self.has_spacer);
+
+        for (index, layout) in layout.children().enumerate() {
+            let bounds = layout.bounds();
+
+            if index >= column_count {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    DEBUG_SPACER_COLOR,
+                );
+                continue;
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        color: DEBUG_COLOR,
+                        width: 1.0,
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+
+            if self.debug == DebugMode::Labeled {
+                renderer.fill_text(
+                    text::Text {
+                        content: index.to_string(),
+                        bounds: bounds.size(),
+                        size: Pixels(10.0),
+                        line_height: text::LineHeight::default(),
+                        font: renderer.default_font(),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                    },
+                    bounds.position() + Vector::new(2.0, 1.0),
+                    DEBUG_COLOR,
+                    *viewport,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Cells<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(cells: Cells<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(cells)
+    }
+}
@@ -0,0 +1,266 @@
+use iced_core::keyboard;
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, Clipboard, Shell};
+use iced_core::{Element, Length, Rectangle, Size, Vector};
+
+#[derive(Debug, Default)]
+struct State {
+    modifiers: keyboard::Modifiers,
+    is_pressed: bool,
+    last_click: Option<mouse::Click>,
+}
+
+/// Produces messages when its content is clicked, unless the content captures the click.
+///
+/// The messages are produced once the button is released over the content, so that a
+/// drag starting on the content, such as one reordering columns, isn't a click. A right
+/// click produces its message right away, while a double click produces its message after
+/// the ones of the second click.
+pub(crate) struct Clickable<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_press: Vec<Box<dyn Fn(keyboard::Modifiers) -> Message + 'a>>,
+    on_right_press: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_double_press: Vec<Box<dyn Fn() -> Message + 'a>>,
+}
+
+impl<'a, Message, Theme, Renderer> Clickable<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_press: Vec::new(),
+            on_right_press: None,
+            on_double_press: Vec::new(),
+        }
+    }
+
+    /// Adds a message produced on click, in order. `on_press` receives the keyboard
+    /// modifiers held during the click.
+    pub fn on_press(mut self, on_press: impl Fn(keyboard::Modifiers) -> Message + 'a) -> Self {
+        self.on_press.push(Box::new(on_press));
+        self
+    }
+
+    /// Sets the message produced on right click.
+    pub fn on_right_press(self, on_right_press: impl Fn() -> Message + 'a) -> Self {
+        Self {
+            on_right_press: Some(Box::new(on_right_press)),
+            ..self
+        }
+    }
+
+    /// Adds a message produced on double click, in order.
+    pub fn on_double_press(mut self, on_double_press: impl Fn() -> Message + 'a) -> Self {
+        self.on_double_press.push(Box::new(on_double_press));
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Clickable<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            event::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if status == event::Status::Ignored
+                    && (!self.on_press.is_empty() || !self.on_double_press.is_empty()) =>
+            {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    state.is_pressed = true;
+                    state.last_click = Some(mouse::Click::new(
+                        position,
+                        mouse::Button::Left,
+                        state.last_click,
+                    ));
+
+                    return event::Status::Captured;
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.is_pressed =>
+            {
+                state.is_pressed = false;
+
+                if cursor.is_over(layout.bounds()) {
+                    for on_press in &self.on_press {
+                        shell.publish((on_press)(state.modifiers));
+                    }
+
+                    let is_double = state
+                        .last_click
+                        .is_some_and(|click| matches!(click.kind(), mouse::click::Kind::Double));
+
+                    if is_double {
+                        for on_double_press in &self.on_double_press {
+                            shell.publish((on_double_press)());
+                        }
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if status == event::Status::Ignored =>
+            {
+                if let Some(on_right_press) = self
+                    .on_right_press
+                    .as_ref()
+                    .filter(|_| cursor.is_over(layout.bounds()))
+                {
+                    shell.publish((on_right_press)());
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::None
+            && !self.on_press.is_empty()
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Clickable<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(clickable: Clickable<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(clickable)
+    }
+}
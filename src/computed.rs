@@ -0,0 +1,283 @@
+//! Display a value derived from each row, without adding it to the row type.
+use std::fmt;
+
+use iced_core::{alignment, text, Color, Element, Length};
+use iced_widget::Text;
+
+use crate::table::{self, Displayed, SummaryLevel};
+
+/// How the values of a [`ComputedColumn`] are aggregated in its footer & summary bands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aggregate {
+    /// The sum of the values.
+    Sum,
+    /// The arithmetic mean of the values.
+    Mean,
+    /// The smallest value.
+    Min,
+    /// The largest value.
+    Max,
+}
+
+impl Aggregate {
+    /// Aggregates the values, returning `None` if there are none.
+    pub fn apply(self, values: impl IntoIterator<Item = f64>) -> Option<f64> {
+        let mut values = values.into_iter();
+        let first = values.next()?;
+
+        Some(match self {
+            Aggregate::Sum => values.fold(first, |sum, value| sum + value),
+            Aggregate::Mean => {
+                let (sum, count) = values.fold((first, 1.0), |(sum, count), value| {
+                    (sum + value, count + 1.0)
+                });
+
+                sum / count
+            }
+            Aggregate::Min => values.fold(first, f64::min),
+            Aggregate::Max => values.fold(first, f64::max),
+        })
+    }
+}
+
+/// A [`Column`](table::Column) displaying a number computed from each row.
+///
+/// The values are right aligned, formatted with a [`precision`](Self::precision) and
+/// optional [thousands separator](Self::thousands_separator) and, if an [`Aggregate`] is
+/// set, aggregated over the displayed rows in the footer & summary bands of the
+/// [`Table`](crate::Table).
+///
+/// To mix it with other kinds of columns, wrap it in your own column type and delegate
+/// to it.
+pub struct ComputedColumn<Row> {
+    header: String,
+    compute: Box<dyn Fn(&Row) -> f64>,
+    format: Option<Box<dyn Fn(f64) -> String>>,
+    precision: usize,
+    thousands_separator: Option<char>,
+    negative_color: Option<Color>,
+    aggregate: Option<Aggregate>,
+    width: f32,
+    default_width: f32,
+}
+
+impl<Row> ComputedColumn<Row> {
+    /// Creates a new [`ComputedColumn`] with the given header, computing the value of each
+    /// row with `compute`.
+    pub fn new(header: impl Into<String>, compute: impl Fn(&Row) -> f64 + 'static) -> Self {
+        Self {
+            header: header.into(),
+            compute: Box::new(compute),
+            format: None,
+            precision: 2,
+            thousands_separator: None,
+            negative_color: None,
+            aggregate: None,
+            width: 100.0,
+            default_width: 100.0,
+        }
+    }
+
+    /// Sets how values are formatted, overriding the [`precision`](Self::precision) and
+    /// [thousands separator](Self::thousands_separator).
+    pub fn format(self, format: impl Fn(f64) -> String + 'static) -> Self {
+        Self {
+            format: Some(Box::new(format)),
+            ..self
+        }
+    }
+
+    /// Sets the number of decimal places of the values, which defaults to two.
+    pub fn precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+
+    /// Sets the separator grouping the thousands of the values, such as `,`.
+    pub fn thousands_separator(self, separator: char) -> Self {
+        Self {
+            thousands_separator: Some(separator),
+            ..self
+        }
+    }
+
+    /// Sets the color of the negative values.
+    pub fn negative_color(self, color: impl Into<Color>) -> Self {
+        Self {
+            negative_color: Some(color.into()),
+            ..self
+        }
+    }
+
+    /// Sets the [`Aggregate`] shown in the footer & summary bands.
+    pub fn aggregate(self, aggregate: Aggregate) -> Self {
+        Self {
+            aggregate: Some(aggregate),
+            ..self
+        }
+    }
+
+    /// Sets the width of the [`ComputedColumn`], which it is also reset to with the divider,
+    /// see [`on_column_reset`](crate::Table::on_column_reset).
+    pub fn width(self, width: f32) -> Self {
+        Self {
+            width,
+            default_width: width,
+            ..self
+        }
+    }
+
+    /// Sets the width of a resized [`ComputedColumn`], as emitted by
+    /// [`on_column_resize`](crate::Table::on_column_resize).
+    pub fn set_width(&mut self, width: f32) {
+        self.width = width;
+    }
+
+    /// Returns the value computed for the row.
+    pub fn value(&self, row: &Row) -> f64 {
+        (self.compute)(row)
+    }
+
+    /// Formats the value as it is displayed.
+    pub fn format_value(&self, value: f64) -> String {
+        if let Some(format) = &self.format {
+            return format(value);
+        }
+
+        let formatted = format!("{value:.*}", self.precision);
+
+        let Some(separator) = self.thousands_separator else {
+            return formatted;
+        };
+
+        let (sign, digits) = match formatted.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", formatted.as_str()),
+        };
+        let (integer, fraction) = match digits.find('.') {
+            Some(point) => digits.split_at(point),
+            None => (digits, ""),
+        };
+
+        let mut grouped = String::with_capacity(formatted.len() + integer.len() / 3);
+
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(separator);
+            }
+
+            grouped.push(digit);
+        }
+
+        format!("{sign}{grouped}{fraction}")
+    }
+
+    fn aggregated<'a, Message, Theme, Renderer>(
+        &self,
+        rows: impl Iterator<Item = &'a Row>,
+    ) -> Option<Element<'a, Message, Theme, Renderer>>
+    where
+        Row: 'a,
+        Theme: iced_widget::text::Catalog + 'a,
+        Theme::Class<'a>: From<iced_widget::text::StyleFn<'a, Theme>>,
+        Renderer: text::Renderer + 'a,
+    {
+        let value = self.aggregate?.apply(rows.map(|row| self.value(row)))?;
+
+        Some(self.text(value).into())
+    }
+
+    fn text<'a, Theme, Renderer>(&self, value: f64) -> Text<'a, Theme, Renderer>
+    where
+        Theme: iced_widget::text::Catalog + 'a,
+        Theme::Class<'a>: From<iced_widget::text::StyleFn<'a, Theme>>,
+        Renderer: text::Renderer,
+    {
+        Text::new(self.format_value(value))
+            .width(Length::Fill)
+            .align_x(alignment::Horizontal::Right)
+            .color_maybe(self.negative_color.filter(|_| value < 0.0))
+    }
+}
+
+impl<Row> fmt::Debug for ComputedColumn<Row> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComputedColumn")
+            .field("header", &self.header)
+            .field("precision", &self.precision)
+            .field("thousands_separator", &self.thousands_separator)
+            .field("negative_color", &self.negative_color)
+            .field("aggregate", &self.aggregate)
+            .field("width", &self.width)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Row, Message, Theme, Renderer> table::Column<'a, Message, Theme, Renderer>
+    for ComputedColumn<Row>
+where
+    Row: 'a,
+    Theme: iced_widget::text::Catalog + 'a,
+    Theme::Class<'a>: From<iced_widget::text::StyleFn<'a, Theme>>,
+    Renderer: text::Renderer + 'a,
+{
+    type Row = Row;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        Text::new(self.header.as_str()).into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        _row_index: usize,
+        row: &'a Self::Row,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        self.text(self.value(row)).into()
+    }
+
+    fn displayed_footer(
+        &'a self,
+        _col_index: usize,
+        displayed: Displayed<'a, '_, Self::Row>,
+    ) -> Option<Element<'a, Message, Theme, Renderer>> {
+        self.aggregated(displayed.iter())
+    }
+
+    fn summary(
+        &'a self,
+        _col_index: usize,
+        _level: SummaryLevel,
+        rows: Displayed<'a, '_, Self::Row>,
+    ) -> Option<Element<'a, Message, Theme, Renderer>> {
+        self.aggregated(rows.iter())
+    }
+
+    fn default_width(&self) -> Option<f32> {
+        Some(self.default_width)
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregate_values() {
+        let values = [4.0, -2.0, 7.0, 3.0];
+
+        assert_eq!(Aggregate::Sum.apply(values), Some(12.0));
+        assert_eq!(Aggregate::Mean.apply(values), Some(3.0));
+        assert_eq!(Aggregate::Min.apply(values), Some(-2.0));
+        assert_eq!(Aggregate::Max.apply(values), Some(7.0));
+    }
+
+    #[test]
+    fn aggregate_nothing() {
+        assert_eq!(Aggregate::Sum.apply([]), None);
+        assert_eq!(Aggregate::Mean.apply([]), None);
+    }
+}
@@ -0,0 +1,130 @@
+//! Load a CSV file into rows of text & the [`ColumnDef`]s displaying them.
+//!
+//! ```no_run
+//! # use iced_table::csv::Csv;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let Csv { columns, rows } = iced_table::csv::from_path("players.csv")?;
+//!
+//! # let _: iced_core::Element<'_, (), iced_widget::Theme, iced_widget::Renderer> =
+//! iced_table::table(&columns, &rows)
+//! # .into();
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+use iced_core::text;
+use iced_widget::Text;
+
+use crate::ColumnDef;
+
+/// The number of rows sampled to estimate the width of the columns.
+const SAMPLES: usize = 100;
+
+/// The rows of a CSV file along with the [`ColumnDef`]s displaying them.
+pub struct Csv<Message, Theme = iced_widget::Theme, Renderer = iced_widget::Renderer> {
+    /// A column for each field of the first line, titled by it.
+    pub columns: Vec<ColumnDef<Vec<String>, Message, Theme, Renderer>>,
+    /// The fields of each following line.
+    pub rows: Vec<Vec<String>>,
+}
+
+impl<Message, Theme, Renderer> fmt::Debug for Csv<Message, Theme, Renderer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Csv")
+            .field("columns", &self.columns)
+            .field("rows", &self.rows.len())
+            .finish()
+    }
+}
+
+/// Loads the CSV file at the given path.
+pub fn from_path<Message, Theme, Renderer>(
+    path: impl AsRef<Path>,
+) -> Result<Csv<Message, Theme, Renderer>, ::csv::Error>
+where
+    Message: 'static,
+    Theme: iced_widget::text::Catalog + 'static,
+    Renderer: text::Renderer + 'static,
+{
+    from_reader(std::fs::File::open(path)?)
+}
+
+/// Loads CSV data from the reader.
+///
+/// The width of each column is estimated from the length of its header & of its values
+/// in the first rows. Lines with fewer fields than the header leave their last cells empty.
+pub fn from_reader<Message, Theme, Renderer>(
+    reader: impl io::Read,
+) -> Result<Csv<Message, Theme, Renderer>, ::csv::Error>
+where
+    Message: 'static,
+    Theme: iced_widget::text::Catalog + 'static,
+    Renderer: text::Renderer + 'static,
+{
+    let mut reader = ::csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(reader);
+
+    let headers = reader.headers()?.clone();
+
+    let rows = reader
+        .records()
+        .map(|record| Ok(record?.iter().map(str::to_owned).collect()))
+        .collect::<Result<Vec<Vec<String>>, ::csv::Error>>()?;
+
+    let columns = headers
+        .iter()
+        .enumerate()
+        .map(|(index, header)| {
+            let longest = rows
+                .iter()
+                .take(SAMPLES)
+                .filter_map(|row| row.get(index))
+                .map(|value| value.chars().count())
+                .chain([header.chars().count()])
+                .max()
+                .unwrap_or_default();
+
+            ColumnDef::new()
+                .header(header)
+                .cell(move |row: &Vec<String>| {
+                    Text::new(row.get(index).map(String::as_str).unwrap_or_default()).into()
+                })
+                .width(estimate_width(longest))
+        })
+        .collect();
+
+    Ok(Csv { columns, rows })
+}
+
+/// Estimates the width fitting text of the given number of characters, within bounds.
+fn estimate_width(chars: usize) -> f32 {
+    (chars as f32 * 8.0 + 16.0).clamp(60.0, 400.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::Column;
+
+    #[test]
+    fn widths_fit_the_longest_sampled_text() {
+        let data = "Name,Id\nAlexandria Ocasio,1\nBo,\n";
+        let Csv { columns, rows } =
+            from_reader::<(), iced_core::Theme, ()>(data.as_bytes()).unwrap();
+
+        assert_eq!(rows, vec![vec!["Alexandria Ocasio", "1"], vec!["Bo", ""]]);
+        assert_eq!(Column::width(&columns[0]), 17.0 * 8.0 + 16.0);
+        assert_eq!(Column::width(&columns[1]), 60.0);
+    }
+
+    #[test]
+    fn estimated_widths_are_bounded() {
+        assert_eq!(estimate_width(0), 60.0);
+        assert_eq!(estimate_width(10), 96.0);
+        assert_eq!(estimate_width(1000), 400.0);
+    }
+}
@@ -1,17 +1,104 @@
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+use std::rc::Rc;
+
 use iced_core::layout::{self, Layout};
 use iced_core::mouse::Cursor;
 use iced_core::widget::{self, Widget};
 use iced_core::{
-    event, mouse, overlay, padding, Color, Element, Length, Point, Rectangle, Size, Vector,
+    event, keyboard, mouse, overlay, padding, touch, Color, Element, Length, Point, Rectangle,
+    Size, Vector,
 };
 use iced_core::{renderer, Clipboard, Shell};
 
 use crate::style;
 
+/// A resize of the column at `position` among the displayed columns, by `offset`.
+///
+/// With `neighbor`, the next displayed column is resized by the opposite offset, keeping the
+/// total width of the columns.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Resize {
+    pub position: usize,
+    pub offset: f32,
+    pub neighbor: bool,
+}
+
+/// The on-going [`Resize`], if any, along with the offset of each displayed column still
+/// animating towards its new width.
+///
+/// It is shared by the dividers, which update it while dragged, and the cells of the table,
+/// which are laid out accordingly until the new width is applied by the consumer.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Resizing {
+    resize: Rc<Cell<Option<Resize>>>,
+    animation: Rc<RefCell<Vec<f32>>>,
+}
+
+impl Resizing {
+    pub fn get(&self) -> Option<Resize> {
+        self.resize.get()
+    }
+
+    pub fn set(&self, resizing: Option<Resize>) {
+        self.resize.set(resizing);
+    }
+
+    /// The offsets of the displayed columns animating towards their width, by position.
+    pub fn animation(&self) -> Vec<f32> {
+        self.animation.borrow().clone()
+    }
+
+    /// Sets the offsets of the displayed columns animating towards their width, by position.
+    pub fn animate(&self, offsets: Vec<f32>) {
+        *self.animation.borrow_mut() = offsets;
+    }
+
+    /// The offset of a cell spanning the given positions.
+    pub fn offset(&self, positions: Range<usize>) -> f32 {
+        let mut offset = self
+            .animation
+            .borrow()
+            .iter()
+            .skip(positions.start)
+            .take(positions.len())
+            .sum();
+
+        let Some(resize) = self.get() else {
+            return offset;
+        };
+
+        if positions.contains(&resize.position) {
+            offset += resize.offset;
+        }
+
+        if resize.neighbor && positions.contains(&(resize.position + 1)) {
+            offset -= resize.offset;
+        }
+
+        offset
+    }
+
+    /// The offset of the total width of the columns.
+    pub fn total_offset(&self) -> f32 {
+        let animation: f32 = self.animation.borrow().iter().sum();
+
+        match self.get() {
+            Some(resize) if !resize.neighbor => animation + resize.offset,
+            _ => animation,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 struct State {
     drag_origin: Option<Point>,
+    drag_offset: f32,
+    // The finger dragging the divider, on touchscreens
+    finger: Option<touch::Finger>,
+    modifiers: keyboard::Modifiers,
     is_divider_hovered: bool,
+    last_click: Option<mouse::Click>,
 }
 
 pub(crate) struct Divider<'a, Message, Theme, Renderer>
@@ -21,9 +108,15 @@ where
 {
     content: Element<'a, Message, Theme, Renderer>,
     width: f32,
-    on_drag: Box<dyn Fn(f32) -> Message + 'a>,
-    on_release: Message,
-    style: <Theme as style::Catalog>::Style,
+    hit_area: f32,
+    position: usize,
+    resizing: Resizing,
+    clamp: Box<dyn Fn(f32) -> f32 + 'a>,
+    on_resize: Box<dyn Fn(f32) -> Message + 'a>,
+    on_neighbor_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    on_double_click: Option<Message>,
+    on_reset: Option<Message>,
+    class: style::Shared<'a, Theme>,
 }
 
 impl<'a, Message, Theme, Renderer> Divider<'a, Message, Theme, Renderer>
@@ -31,19 +124,46 @@ where
     Renderer: renderer::Renderer,
     Theme: style::Catalog,
 {
+    /// Resizes the column at `position` among the displayed columns, where `clamp` returns
+    /// the allowed offset for the dragged one, and `on_resize` the message produced with the
+    /// final offset.
+    ///
+    /// The divider can be grabbed up to `hit_area` away from each of its sides. Clicking it
+    /// while holding the command modifier produces `on_reset`, instead.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
         width: f32,
-        on_drag: impl Fn(f32) -> Message + 'a,
-        on_release: Message,
-        style: <Theme as style::Catalog>::Style,
+        hit_area: f32,
+        position: usize,
+        resizing: Resizing,
+        clamp: impl Fn(f32) -> f32 + 'a,
+        on_resize: impl Fn(f32) -> Message + 'a,
+        on_double_click: Option<Message>,
+        on_reset: Option<Message>,
+        class: style::Shared<'a, Theme>,
     ) -> Self {
         Self {
             content: content.into(),
             width,
-            on_drag: Box::new(on_drag),
-            on_release,
-            style,
+            hit_area,
+            position,
+            resizing,
+            clamp: Box::new(clamp),
+            on_resize: Box::new(on_resize),
+            on_neighbor_resize: None,
+            on_double_click,
+            on_reset,
+            class,
+        }
+    }
+
+    /// Resizes the next displayed column by the opposite offset, where `on_resize` is the
+    /// message produced with the final offset of the dragged column.
+    pub fn neighbor(self, on_resize: impl Fn(f32) -> Message + 'a) -> Self {
+        Self {
+            on_neighbor_resize: Some(Box::new(on_resize)),
+            ..self
         }
     }
 
@@ -57,16 +177,76 @@ where
 
     fn divider_hover_bounds(&self, bounds: Rectangle) -> Rectangle {
         let mut bounds = self.divider_bounds(bounds);
-        // TODO: Configurable
-        bounds.x -= 5.0;
-        bounds.width += 10.0;
+        bounds.x -= self.hit_area;
+        bounds.width += self.hit_area * 2.0;
 
         bounds
     }
 
+    // Starts dragging the divider from `origin`, unless it is a double click or a reset
+    fn press(&self, state: &mut State, origin: Point, shell: &mut Shell<'_, Message>)
+    where
+        Message: Clone,
+    {
+        if let Some(on_reset) = self.on_reset.clone().filter(|_| state.modifiers.command()) {
+            state.finger = None;
+            shell.publish(on_reset);
+
+            return;
+        }
+
+        let click = mouse::Click::new(origin, mouse::Button::Left, state.last_click);
+
+        state.last_click = Some(click);
+
+        match (&self.on_double_click, click.kind()) {
+            (Some(on_double_click), mouse::click::Kind::Double) => {
+                state.finger = None;
+                shell.publish(on_double_click.clone());
+            }
+            _ => {
+                state.drag_origin = Some(origin);
+                state.drag_offset = 0.0;
+            }
+        }
+    }
+
+    // Resizes the column to `position`, returning whether the divider is being dragged
+    fn drag(&self, state: &mut State, position: Point, shell: &mut Shell<'_, Message>) -> bool {
+        let Some(origin) = state.drag_origin else {
+            return false;
+        };
+
+        state.drag_offset = (self.clamp)((position - origin).x);
+        self.resizing.set(Some(Resize {
+            position: self.position,
+            offset: state.drag_offset,
+            neighbor: self.on_neighbor_resize.is_some(),
+        }));
+
+        shell.invalidate_layout();
+        true
+    }
+
+    // Finishes the drag, publishing the final offset
+    fn release(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.drag_origin = None;
+        self.resizing.set(None);
+
+        if state.drag_offset != 0.0 {
+            shell.publish((self.on_resize)(state.drag_offset));
+
+            if let Some(on_neighbor_resize) = &self.on_neighbor_resize {
+                shell.publish((on_neighbor_resize)(state.drag_offset));
+            }
+        }
+
+        shell.invalidate_layout();
+    }
+
     fn is_content_hovered(&self, mut bounds: Rectangle, cursor: Cursor) -> bool {
         // Ignore left edge to not conflict with other dividers
-        bounds.x = (bounds.x + 5.0).min(bounds.x + bounds.width - 5.0);
+        bounds.x = (bounds.x + self.hit_area).min(bounds.x + bounds.width - self.hit_area);
 
         cursor.is_over(bounds)
     }
@@ -114,6 +294,7 @@ where
         })
     }
 
+    #[allow(clippy::collapsible_match)]
     fn on_event(
         &mut self,
         tree: &mut widget::Tree,
@@ -131,30 +312,59 @@ where
 
         state.is_divider_hovered = cursor.is_over(divider_hover_bounds);
 
-        if let event::Event::Mouse(event) = event {
-            match event {
-                mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                    if let Some(origin) = cursor.position_over(divider_hover_bounds) {
-                        state.drag_origin = Some(origin);
-                        return event::Status::Captured;
-                    }
+        // The cursor follows the finger on touchscreens, translated as the mouse cursor
+        match event {
+            event::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(origin) = cursor.position_over(divider_hover_bounds) {
+                    self.press(state, origin, shell);
+
+                    return event::Status::Captured;
                 }
-                mouse::Event::ButtonReleased(mouse::Button::Left) => {
-                    if state.drag_origin.take().is_some() {
-                        shell.publish(self.on_release.clone());
-                        return event::Status::Captured;
-                    }
+            }
+            event::Event::Touch(touch::Event::FingerPressed { id, .. })
+                if state.finger.is_none() =>
+            {
+                if let Some(origin) = cursor.position_over(divider_hover_bounds) {
+                    state.finger = Some(id);
+                    self.press(state, origin, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.finger.is_none() && state.drag_origin.take().is_some() {
+                    self.release(state, shell);
+
+                    return event::Status::Captured;
                 }
-                mouse::Event::CursorMoved { .. } => {
-                    if let Some(position) = cursor.position() {
-                        if let Some(origin) = state.drag_origin {
-                            shell.publish((self.on_drag)((position - origin).x));
-                            return event::Status::Captured;
-                        }
+            }
+            event::Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) if state.finger == Some(id) => {
+                state.finger = None;
+                self.release(state, shell);
+
+                return event::Status::Captured;
+            }
+            event::Event::Mouse(mouse::Event::CursorMoved { .. })
+            | event::Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let is_dragging = match event {
+                    event::Event::Touch(touch::Event::FingerMoved { id, .. }) => {
+                        state.finger == Some(id)
+                    }
+                    _ => state.finger.is_none(),
+                };
+
+                if let Some(position) = cursor.position().filter(|_| is_dragging) {
+                    if self.drag(state, position, shell) {
+                        return event::Status::Captured;
                     }
                 }
-                _ => {}
             }
+            _ => {}
         }
 
         self.content.as_widget_mut().on_event(
@@ -218,9 +428,12 @@ where
             || state.is_divider_hovered
             || state.drag_origin.is_some()
         {
-            let appearance = theme.divider(
-                &self.style,
-                state.is_divider_hovered || state.drag_origin.is_some(),
+            let appearance = theme.style(
+                &self.class,
+                style::Status::Divider {
+                    hovered: state.is_divider_hovered,
+                    dragged: state.drag_origin.is_some(),
+                },
             );
 
             let snap = |bounds: Rectangle| {
@@ -253,7 +466,7 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
         translation: Vector,
-    ) -> Option<overlay::Element<'_, Message, Theme, Renderer>> {
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         self.content.as_widget_mut().overlay(
             &mut tree.children[0],
             layout.children().next().unwrap(),
@@ -0,0 +1,227 @@
+use std::rc::Rc;
+
+use iced_core::keyboard::{self, key};
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, Clipboard, Shell};
+use iced_core::{Element, Length, Rectangle, Size, Vector};
+
+use crate::table::CellEdit;
+
+/// Commits the edited cell with Enter, cancels it with Escape, and moves to the next (or
+/// previous, with Shift) editable cell with Tab. The keys are handled before the content,
+/// so that the editor doesn't swallow them, except for Enter which reaches the content
+/// first, so that an editor such as an [`autocomplete`](crate::autocomplete) can apply its
+/// highlighted suggestion before the commit.
+///
+/// `displayed` & `editable` are the indices of the displayed rows & editable columns, in
+/// display order, which Tab moves through row by row.
+///
+/// The wrapper is transparent: its widget tree is the one of its content.
+pub(crate) struct Editing<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    displayed: Vec<usize>,
+    editable: Vec<usize>,
+    editing: (usize, usize),
+    on_edit: Rc<dyn Fn(CellEdit) -> Message + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Editing<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        displayed: Vec<usize>,
+        editable: Vec<usize>,
+        editing: (usize, usize),
+        on_edit: Rc<dyn Fn(CellEdit) -> Message + 'a>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            displayed,
+            editable,
+            editing,
+            on_edit,
+        }
+    }
+
+    // The editable cell before or after the edited cell, if any
+    fn next(&self, backwards: bool) -> Option<(usize, usize)> {
+        let (row, column) = self.editing;
+        let row = self.displayed.iter().position(|index| *index == row)?;
+        let column = self.editable.iter().position(|index| *index == column)?;
+
+        let (row, column) = if backwards {
+            match column.checked_sub(1) {
+                Some(column) => (row, column),
+                None => (row.checked_sub(1)?, self.editable.len() - 1),
+            }
+        } else if column + 1 < self.editable.len() {
+            (row, column + 1)
+        } else {
+            (row + 1, 0)
+        };
+
+        Some((*self.displayed.get(row)?, self.editable[column]))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Editing<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            modifiers,
+            ..
+        }) = &event
+        {
+            let (row, column) = self.editing;
+
+            match key {
+                key::Named::Enter => {
+                    let _ = self.content.as_widget_mut().on_event(
+                        tree,
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    );
+
+                    shell.publish((self.on_edit)(CellEdit::Commit { row, column }));
+
+                    return event::Status::Captured;
+                }
+                key::Named::Escape => {
+                    shell.publish((self.on_edit)(CellEdit::Cancel { row, column }));
+
+                    return event::Status::Captured;
+                }
+                key::Named::Tab => {
+                    shell.publish((self.on_edit)(CellEdit::Commit { row, column }));
+
+                    if let Some((row, column)) = self.next(modifiers.shift()) {
+                        shell.publish((self.on_edit)(CellEdit::Start { row, column }));
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Editing<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(editing: Editing<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(editing)
+    }
+}
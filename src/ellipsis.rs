@@ -0,0 +1,379 @@
+//! Display text that is truncated with an ellipsis when it doesn't fit its cell.
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::Cursor;
+use iced_core::text::{self, Paragraph as _};
+use iced_core::time::Duration;
+use iced_core::widget::{self, Widget};
+use iced_core::{alignment, event, overlay, renderer, Clipboard, Shell};
+use iced_core::{Element, Length, Pixels, Rectangle, Size, Vector};
+use iced_widget::container;
+use iced_widget::tooltip::Position;
+
+use crate::style;
+use crate::tooltip::{Hover, Overlay};
+
+const ELLIPSIS: &str = "…";
+
+/// Creates a new [`EllipsisText`] with the given content.
+///
+/// The text is kept on a single line and truncated with "…" when it is wider than
+/// the space available, in which case the full content is shown in a tooltip on hover.
+pub fn ellipsis_text<'a, Message, Theme, Renderer>(
+    content: impl text::IntoFragment<'a>,
+) -> EllipsisText<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog + iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    EllipsisText::new(content)
+}
+
+/// Single line text which is truncated with an ellipsis when too narrow.
+#[allow(missing_debug_implementations)]
+pub struct EllipsisText<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+    Renderer: text::Renderer,
+{
+    content: Cow<'a, str>,
+    size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    tooltip: Element<'a, Message, Theme, Renderer>,
+    position: Position,
+    delay: Duration,
+    class: <Theme as style::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> EllipsisText<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog + iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    /// Creates a new [`EllipsisText`] with the given content.
+    pub fn new(content: impl text::IntoFragment<'a>) -> Self {
+        let content = content.into_fragment();
+
+        Self {
+            tooltip: full_text(content.clone(), None, None),
+            content,
+            size: None,
+            font: None,
+            position: Position::FollowCursor,
+            delay: Duration::from_millis(500),
+            class: <Theme as style::Catalog>::default(),
+        }
+    }
+
+    /// Sets the size of the [`EllipsisText`].
+    pub fn size(self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        Self {
+            tooltip: full_text(self.content.clone(), Some(size), self.font),
+            size: Some(size),
+            ..self
+        }
+    }
+
+    /// Sets the font of the [`EllipsisText`].
+    pub fn font(self, font: impl Into<Renderer::Font>) -> Self {
+        let font = font.into();
+
+        Self {
+            tooltip: full_text(self.content.clone(), self.size, Some(font)),
+            font: Some(font),
+            ..self
+        }
+    }
+
+    /// Sets the [`Position`] of the full text tooltip.
+    pub fn tooltip_position(self, position: Position) -> Self {
+        Self { position, ..self }
+    }
+
+    /// Sets how long the text must be hovered before the full text tooltip is shown.
+    pub fn tooltip_delay(self, delay: Duration) -> Self {
+        Self { delay, ..self }
+    }
+
+    /// Sets the style of the full text tooltip.
+    pub fn style(self, style: impl Fn(&Theme, style::Status) -> container::Style + 'a) -> Self
+    where
+        <Theme as style::Catalog>::Class<'a>: From<style::StyleFn<'a, Theme>>,
+    {
+        Self {
+            class: (Rc::new(style) as style::StyleFn<'a, Theme>).into(),
+            ..self
+        }
+    }
+
+    /// Sets the style class of the full text tooltip.
+    pub fn class(self, class: impl Into<<Theme as style::Catalog>::Class<'a>>) -> Self {
+        Self {
+            class: class.into(),
+            ..self
+        }
+    }
+}
+
+fn full_text<'a, Message, Theme, Renderer>(
+    content: Cow<'a, str>,
+    size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    let mut text = iced_widget::Text::new(content);
+
+    if let Some(size) = size {
+        text = text.size(size);
+    }
+    if let Some(font) = font {
+        text = text.font(font);
+    }
+
+    text.into()
+}
+
+struct State<P: text::Paragraph> {
+    paragraph: P,
+    content: String,
+    size: Option<Pixels>,
+    font: Option<P::Font>,
+    max_width: f32,
+    is_truncated: bool,
+    hover: Hover,
+}
+
+impl<'a, Message, Theme, Renderer> EllipsisText<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+    Renderer: text::Renderer,
+{
+    fn text<'b>(
+        &self,
+        content: &'b str,
+        renderer: &Renderer,
+    ) -> text::Text<&'b str, Renderer::Font> {
+        text::Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+        }
+    }
+
+    /// Finds the longest prefix of the content which fits in `max_width` once
+    /// the ellipsis is appended.
+    fn truncate(&self, renderer: &Renderer, max_width: f32) -> Renderer::Paragraph {
+        let boundaries: Vec<usize> = self.content.char_indices().map(|(i, _)| i).collect();
+
+        let truncated = |count: usize| {
+            let end = boundaries.get(count).copied().unwrap_or(self.content.len());
+            let content = format!("{}{ELLIPSIS}", self.content[..end].trim_end());
+
+            Renderer::Paragraph::with_text(self.text(&content, renderer))
+        };
+
+        truncated(longest_fitting(boundaries.len(), |count| {
+            truncated(count).min_width() <= max_width
+        }))
+    }
+}
+
+/// Finds the largest count up to `max` which `fits` with a binary search, assuming any
+/// smaller count fits too. Zero is returned if nothing fits.
+fn longest_fitting(max: usize, fits: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, max);
+
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+
+        if fits(mid) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    low
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for EllipsisText<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::<Renderer::Paragraph> {
+            paragraph: Default::default(),
+            content: String::new(),
+            size: None,
+            font: None,
+            max_width: f32::NAN,
+            is_truncated: false,
+            hover: Hover::default(),
+        })
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.tooltip)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[&self.tooltip]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let max_width = limits.max().width;
+
+        if state.content != self.content
+            || state.size != self.size
+            || state.font != self.font
+            || state.max_width != max_width
+        {
+            let full = Renderer::Paragraph::with_text(self.text(&self.content, renderer));
+
+            state.is_truncated = full.min_width() > max_width;
+            state.paragraph = if state.is_truncated {
+                self.truncate(renderer, max_width)
+            } else {
+                full
+            };
+            state.content = self.content.to_string();
+            state.size = self.size;
+            state.font = self.font;
+            state.max_width = max_width;
+        }
+
+        let width = if max_width.is_finite() {
+            Length::Fill
+        } else {
+            Length::Shrink
+        };
+
+        layout::Node::new(limits.resolve(width, Length::Shrink, state.paragraph.min_bounds()))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if state.is_truncated {
+            state
+                .hover
+                .update(&event, layout.bounds(), cursor, self.delay, shell);
+        } else {
+            state.hover = Hover::Idle;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        renderer.fill_paragraph(
+            &state.paragraph,
+            layout.bounds().position(),
+            style.text_color,
+            *viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let cursor_position = state.hover.visible(self.delay)?;
+
+        Some(overlay::Element::new(Box::new(Overlay::new(
+            layout.position() + translation,
+            &self.tooltip,
+            &mut tree.children[0],
+            cursor_position,
+            layout.bounds(),
+            self.position,
+            &self.class,
+        ))))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<EllipsisText<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: style::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(text: EllipsisText<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_fitting_count() {
+        for max in 0..20 {
+            for limit in 0..25 {
+                assert_eq!(longest_fitting(max, |count| count <= limit), limit.min(max));
+            }
+        }
+    }
+
+    #[test]
+    fn nothing_fitting() {
+        assert_eq!(longest_fitting(10, |_| false), 0);
+    }
+}
@@ -0,0 +1,301 @@
+use std::any::Any;
+
+use iced_core::keyboard::{self, key};
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::operation::Focusable;
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, window, Clipboard, Shell};
+use iced_core::{Element, Length, Rectangle, Size, Vector};
+
+/// The row & column of the body cell whose widgets are operated on next, published by
+/// [`Cells`](crate::cells::Cells) before each of its cells, or `None` once past them.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Cell(pub Option<(usize, usize)>);
+
+/// Moves the focus to the next focusable widget of the table with Tab, or to the
+/// previous one with Shift+Tab, unless the content captures them.
+///
+/// The widgets are focused in row-major order, which is the order of the widget tree.
+/// Past the first or last focusable widget of the table, the key is left to the
+/// application.
+///
+/// The wrapper is transparent: its widget tree is the one of its content.
+pub(crate) struct Tabbing<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Tabbing<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabbing<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            tree,
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let event::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key::Named::Tab),
+            modifiers,
+            ..
+        }) = event
+        {
+            let mut count = Count::default();
+
+            self.content
+                .as_widget()
+                .operate(tree, layout, renderer, &mut count);
+
+            let next = count.focused.and_then(|focused| {
+                if modifiers.shift() {
+                    focused.checked_sub(1)
+                } else {
+                    Some(focused + 1).filter(|next| *next < count.total)
+                }
+            });
+
+            if let Some(next) = next {
+                self.content.as_widget().operate(
+                    tree,
+                    layout,
+                    renderer,
+                    &mut FocusIndex {
+                        target: next,
+                        current: 0,
+                    },
+                );
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tabbing<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(tabbing: Tabbing<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tabbing)
+    }
+}
+
+/// Counts the focusable widgets, along with the index of the focused one.
+#[derive(Debug, Default)]
+struct Count {
+    focused: Option<usize>,
+    total: usize,
+}
+
+impl Operation for Count {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&widget::Id>) {
+        if state.is_focused() {
+            self.focused = Some(self.total);
+        }
+
+        self.total += 1;
+    }
+}
+
+/// Focuses the focusable widget at the `target` index, unfocusing the others.
+#[derive(Debug)]
+struct FocusIndex {
+    target: usize,
+    current: usize,
+}
+
+impl Operation for FocusIndex {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&widget::Id>) {
+        if self.current == self.target {
+            state.focus();
+        } else {
+            state.unfocus();
+        }
+
+        self.current += 1;
+    }
+}
+
+/// Focuses the first focusable widget of the body cell at the given row & column,
+/// unfocusing the others.
+#[derive(Debug)]
+pub(crate) struct FocusCell {
+    target: (usize, usize),
+    current: Option<(usize, usize)>,
+    focused: bool,
+}
+
+impl FocusCell {
+    pub fn new(row: usize, column: usize) -> Self {
+        Self {
+            target: (row, column),
+            current: None,
+            focused: false,
+        }
+    }
+}
+
+impl Operation for FocusCell {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&widget::Id>) {
+        if !self.focused && self.current == Some(self.target) {
+            state.focus();
+            self.focused = true;
+        } else {
+            state.unfocus();
+        }
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&widget::Id>) {
+        if let Some(cell) = state.downcast_ref::<Cell>() {
+            self.current = cell.0;
+        }
+    }
+}
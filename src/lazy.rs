@@ -0,0 +1,494 @@
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+use std::rc::Rc;
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, Clipboard, Shell};
+use iced_core::{Element, Length, Point, Rectangle, Size, Vector};
+
+/// Builds the element of a row once it is scrolled into view.
+pub(crate) type Build<'a, Message, Theme, Renderer> =
+    Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+// A lazy row, with the index of the row it displays
+struct Builder<'a, Message, Theme, Renderer> {
+    row: usize,
+    height: f32,
+    build: Build<'a, Message, Theme, Renderer>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    // The rows of the lazy entries built by the last layout
+    rows: Option<Range<usize>>,
+    // The rows last reported with the message
+    reported: Option<Range<usize>>,
+}
+
+/// The part of the body visible through its scrollable, as its vertical offset & height.
+///
+/// It is kept by the bands across views, and read by the [`Lazy`] rows of the body as
+/// they are laid out.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Visible(Rc<Cell<Option<(f32, f32)>>>);
+
+impl Visible {
+    pub fn get(&self) -> Option<(f32, f32)> {
+        self.0.get()
+    }
+
+    pub fn set(&self, visible: Option<(f32, f32)>) {
+        self.0.set(visible);
+    }
+}
+
+/// A child of the [`Lazy`] rows: either built along with the view, or built once scrolled
+/// into view, given the index of its row & its height.
+pub(crate) enum Entry<'a, Message, Theme, Renderer> {
+    Built(Element<'a, Message, Theme, Renderer>),
+    Lazy {
+        row: usize,
+        height: f32,
+        build: Build<'a, Message, Theme, Renderer>,
+    },
+}
+
+impl<'a, Message, Theme, Renderer> Entry<'a, Message, Theme, Renderer> {
+    /// Builds the entry right away.
+    pub fn build(self) -> Element<'a, Message, Theme, Renderer> {
+        match self {
+            Self::Built(element) => element,
+            Self::Lazy { build, .. } => build(),
+        }
+    }
+}
+
+/// Stacks the rows of the body vertically, only building the lazy rows within the
+/// [`Visible`] part of the body, extended by `overscan` lazy rows beyond each edge.
+///
+/// The rows built along with the view, such as the bands of groups & summaries, are laid
+/// out first, so the lazy rows are placed after their actual height. Lazy rows out of view
+/// keep their height, with an empty layout.
+///
+/// The range of the rows built, once changed, is published with the `on_rows_visible`
+/// message.
+pub(crate) struct Lazy<'a, Message, Theme, Renderer> {
+    // The builder of each lazy entry
+    builders: Vec<Option<Builder<'a, Message, Theme, Renderer>>>,
+    // The element of each entry, once built & for as long as it is in view
+    elements: RefCell<Vec<Option<Element<'a, Message, Theme, Renderer>>>>,
+    visible: Visible,
+    overscan: usize,
+    on_rows_visible: Option<Rc<dyn Fn(Range<usize>) -> Message + 'a>>,
+}
+
+impl<'a, Message, Theme, Renderer> Lazy<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        entries: impl IntoIterator<Item = Entry<'a, Message, Theme, Renderer>>,
+        visible: Visible,
+        overscan: usize,
+    ) -> Self {
+        let (builders, elements) = entries
+            .into_iter()
+            .map(|entry| match entry {
+                Entry::Built(element) => (None, Some(element)),
+                Entry::Lazy { row, height, build } => (Some(Builder { row, height, build }), None),
+            })
+            .unzip();
+
+        Self {
+            builders,
+            elements: RefCell::new(elements),
+            visible,
+            overscan,
+            on_rows_visible: None,
+        }
+    }
+
+    pub fn on_rows_visible(
+        self,
+        on_rows_visible: Option<Rc<dyn Fn(Range<usize>) -> Message + 'a>>,
+    ) -> Self {
+        Self {
+            on_rows_visible,
+            ..self
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Lazy<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.elements
+            .borrow()
+            .iter()
+            .map(|element| element.as_ref().map_or_else(Tree::empty, Tree::new))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let elements = self.elements.borrow();
+
+        tree.children.truncate(elements.len());
+        tree.children.resize_with(elements.len(), Tree::empty);
+
+        // The lazy rows are diffed once built, keeping their previous state until then
+        for (tree, element) in tree.children.iter_mut().zip(elements.iter()) {
+            if let Some(element) = element {
+                tree.diff(element.as_widget());
+            }
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let mut elements = self.elements.borrow_mut();
+        let child_limits = layout::Limits::new(Size::ZERO, limits.max());
+
+        let mut nodes: Vec<_> = self
+            .builders
+            .iter()
+            .zip(elements.iter())
+            .zip(&mut tree.children)
+            .map(|((builder, element), tree)| match (builder, element) {
+                (None, Some(element)) => element.as_widget().layout(tree, renderer, &child_limits),
+                (builder, _) => layout::Node::new(Size::new(
+                    0.0,
+                    builder.as_ref().map_or(0.0, |builder| builder.height),
+                )),
+            })
+            .collect();
+
+        let entries: Vec<_> = nodes
+            .iter()
+            .zip(&self.builders)
+            .map(|(node, builder)| (node.size().height, builder.is_some()))
+            .collect();
+
+        let window = match self.visible.get() {
+            Some((offset_y, height)) => virtual_window(&entries, offset_y, height, self.overscan),
+            None => 0..0,
+        };
+
+        let mut rows: Option<Range<usize>> = None;
+
+        for (index, (builder, element)) in self.builders.iter().zip(elements.iter_mut()).enumerate()
+        {
+            let Some(builder) = builder else {
+                continue;
+            };
+
+            if !window.contains(&index) {
+                *element = None;
+                continue;
+            }
+
+            rows = Some(match rows {
+                Some(rows) => rows.start.min(builder.row)..rows.end.max(builder.row + 1),
+                None => builder.row..builder.row + 1,
+            });
+
+            let element = element.get_or_insert_with(|| {
+                let element = (builder.build)();
+
+                tree.children[index].diff(element.as_widget());
+
+                element
+            });
+
+            nodes[index] =
+                element
+                    .as_widget()
+                    .layout(&mut tree.children[index], renderer, &child_limits);
+        }
+
+        tree.state.downcast_mut::<State>().rows = rows;
+
+        let mut y = 0.0;
+        let mut width: f32 = 0.0;
+
+        for node in &mut nodes {
+            let size = node.size();
+
+            node.move_to_mut(Point::new(0.0, y));
+
+            y += size.height;
+            width = width.max(size.width);
+        }
+
+        // The rows out of view span the width of the others, as if they were built
+        for (node, element) in nodes.iter_mut().zip(elements.iter()) {
+            if element.is_none() {
+                let bounds = node.bounds();
+
+                *node =
+                    layout::Node::new(Size::new(width, bounds.height)).move_to(bounds.position());
+            }
+        }
+
+        layout::Node::with_children(
+            limits.resolve(Length::Shrink, Length::Shrink, Size::new(width, y)),
+            nodes,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let elements = self.elements.borrow();
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((element, tree), layout) in elements
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                if let Some(element) = element {
+                    element
+                        .as_widget()
+                        .operate(tree, layout, renderer, operation);
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Some(on_rows_visible) = &self.on_rows_visible {
+            let state = tree.state.downcast_mut::<State>();
+
+            if state.rows != state.reported {
+                state.reported.clone_from(&state.rows);
+
+                if let Some(rows) = state.rows.clone() {
+                    shell.publish((on_rows_visible)(rows));
+                }
+            }
+        }
+
+        self.elements
+            .get_mut()
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((element, tree), layout)| {
+                element.as_mut().map(|element| {
+                    element.as_widget_mut().on_event(
+                        tree,
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    )
+                })
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.elements
+            .borrow()
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter_map(|((element, tree), layout)| {
+                element.as_ref().map(|element| {
+                    element
+                        .as_widget()
+                        .mouse_interaction(tree, layout, cursor, viewport, renderer)
+                })
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((element, tree), layout) in self
+            .elements
+            .borrow()
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            if let Some(element) = element
+                .as_ref()
+                .filter(|_| layout.bounds().intersects(viewport))
+            {
+                element
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children: Vec<_> = self
+            .elements
+            .get_mut()
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((element, tree), layout)| {
+                element
+                    .as_mut()?
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, translation)
+            })
+            .collect();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Lazy<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(lazy: Lazy<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(lazy)
+    }
+}
+
+/// The range of entries to build, given the height of each entry & whether it is lazy: the
+/// entries intersecting the visible part of the body, extended by `overscan` lazy entries
+/// beyond each edge.
+pub(crate) fn virtual_window(
+    entries: &[(f32, bool)],
+    offset_y: f32,
+    visible_height: f32,
+    overscan: usize,
+) -> Range<usize> {
+    let mut start = entries.len();
+    let mut end = entries.len();
+    let mut y = 0.0;
+
+    for (index, (height, _)) in entries.iter().enumerate() {
+        if y >= offset_y + visible_height {
+            end = index;
+            break;
+        }
+
+        y += height;
+
+        if start == entries.len() && y > offset_y {
+            start = index;
+        }
+    }
+
+    let start = start.min(end);
+
+    // Extended by the given number of lazy entries beyond each edge
+    let start = entries[..start]
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, (_, is_lazy))| *is_lazy)
+        .take(overscan)
+        .last()
+        .map_or(start, |(index, _)| index);
+
+    let end = entries[end..]
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, is_lazy))| *is_lazy)
+        .take(overscan)
+        .last()
+        .map_or(end, |(index, _)| end + index + 1);
+
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_of_visible_rows() {
+        let entries = [(20.0, true); 10];
+
+        assert_eq!(virtual_window(&entries, 100.0, 60.0, 0), 5..8);
+        assert_eq!(virtual_window(&entries, 90.0, 60.0, 0), 4..8);
+        assert_eq!(virtual_window(&entries, 100.0, 60.0, 2), 3..10);
+        assert_eq!(virtual_window(&entries, 1000.0, 60.0, 2), 8..10);
+    }
+
+    #[test]
+    fn overscan_skips_eager_entries() {
+        let entries = [
+            (20.0, true),
+            (20.0, true),
+            (20.0, true),
+            (30.0, false),
+            (20.0, true),
+            (20.0, true),
+        ];
+
+        assert_eq!(virtual_window(&entries, 60.0, 30.0, 0), 3..4);
+        assert_eq!(virtual_window(&entries, 60.0, 30.0, 1), 2..5);
+    }
+}
@@ -1,50 +1,295 @@
 //! A table widget for iced
 #![deny(missing_debug_implementations, missing_docs)]
+#[cfg(feature = "helpers")]
+pub use autocomplete::autocomplete;
+#[cfg(feature = "helpers")]
+pub use computed::ComputedColumn;
+#[cfg(feature = "helpers")]
+pub use ellipsis::ellipsis_text;
+#[cfg(feature = "derive")]
+/// Derives the columns of a row struct with named fields, one for each field.
+///
+/// This generates a `{Row}Column` type implementing [`Column`](table::Column), with an
+/// `all` function returning every column in the order of the fields. Its cells are
+/// [plain](table::Column::is_plain) text, displaying the fields with [`ToString`].
+///
+/// ```no_run
+/// #[derive(iced_table::Column)]
+/// struct Player {
+///     #[column(title = "Player", width = 160.0)]
+///     name: String,
+///     #[column(format = "{:.1}%")]
+///     accuracy: f32,
+///     #[column(skip)]
+///     id: u64,
+/// }
+///
+/// let columns = PlayerColumn::all();
+/// ```
+///
+/// The fields accept these attributes:
+/// - `title`: the header of the column, which defaults to the name of the field in title case.
+/// - `width`: the width of the column, which it is also reset to, which defaults to `100.0`.
+/// - `format`: the [`format!`] string of the field, such as `"{:.2}"`.
+/// - `skip`: leaves the field out of the columns.
+///
+/// The row struct accepts `#[column(name = "...")]` to rename the generated type.
+pub use iced_table_derive::Column;
+#[cfg(feature = "serde")]
+pub use persist::TableLayout;
+#[cfg(feature = "helpers")]
+pub use simple::{ColumnDef, SimpleColumn};
+#[cfg(feature = "helpers")]
+pub use sortable::sortable_header;
 pub use style::Catalog;
-pub use table::{table, Table};
+pub use table::{table, table_iter, table_paged, table_with, Table};
 
+#[cfg(feature = "helpers")]
+pub mod aggregate;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "helpers")]
+pub mod autocomplete;
+#[cfg(feature = "helpers")]
+pub mod computed;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "helpers")]
+pub mod ellipsis;
+pub mod operation;
+#[cfg(feature = "serde")]
+pub mod persist;
+#[cfg(feature = "helpers")]
+pub mod presets;
+#[cfg(feature = "helpers")]
+pub mod simple;
+#[cfg(feature = "helpers")]
+pub mod sortable;
+pub mod style;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __derive {
+    pub use iced_core;
+    pub use iced_widget;
+}
+
+mod anchor;
+mod animation;
+mod bands;
+mod cache;
+mod cells;
+mod clickable;
+mod copier;
 mod divider;
-mod style;
+mod editing;
+mod focus;
+mod hover;
+#[cfg(feature = "tracing")]
+mod instrument;
+mod keyed;
+mod lazy;
+mod navigator;
+pub mod paged;
+mod plain;
+mod reorder;
+mod root;
+mod skeleton;
+mod sort;
+mod stats;
+mod tooltip;
 
 pub mod table {
     //! Display rows of data into columns
-    use iced_core::{Element, Length, Padding};
-    use iced_widget::{column, container, row, scrollable, Space};
+    use std::borrow::Cow;
+    use std::collections::HashSet;
+    use std::fmt;
+    use std::hash::{DefaultHasher, Hash, Hasher};
+    use std::ops::Range;
+    use std::rc::Rc;
+
+    use iced_core::time::{Duration, Instant};
+    use iced_core::{alignment, keyboard, widget, Element, Length, Padding, Point, Size};
+    use iced_widget::{button, column, container, row, scrollable, text, tooltip, Space};
 
-    use super::divider::Divider;
-    use super::style;
+    use super::anchor::Anchor;
+    use super::animation::Animated;
+    use super::bands::{Bands, RightPress};
+    use super::cache::Cached;
+    use super::cells::{Cells, DebugMode};
+    use super::clickable::Clickable;
+    use super::copier::Copier;
+    use super::divider::{Divider, Resizing};
+    use super::editing::Editing;
+    use super::focus::Tabbing;
+    use super::hover::Hover;
+    use super::keyed::Keyed;
+    use super::lazy::{Build, Entry, Lazy, Visible};
+    use super::navigator::Navigator;
+    use super::paged::Paged;
+    use super::plain::Plain;
+    use super::reorder::{Axis, Reorder};
+    use super::root::Root;
+    use super::skeleton::Skeleton;
+    use super::stats::{Built, Reporter};
+    use super::style::wrapper::StyledRow;
+    use super::style::{self, RowStyle};
+    use super::tooltip::Tooltip;
+
+    pub use super::sort::SortModel;
 
     /// Creates a new [`Table`] with the provided [`Column`] definitions
     /// and [`Row`](Column::Row) data.
     ///
-    /// `on_sync` is needed to keep the header & footer scrollables in sync with
-    /// the body scrollable. It is up to the consumer to emit a [`scroll_to`](iced_widget::scrollable::scroll_to) operation
-    /// from `update` when this message is received.
+    /// The header & footer scroll horizontally along with the body on their own.
     pub fn table<'a, Column, Row, Message, Theme>(
-        header: scrollable::Id,
-        body: scrollable::Id,
         columns: &'a [Column],
         rows: &'a [Row],
-        on_sync: fn(scrollable::AbsoluteOffset) -> Message,
     ) -> Table<'a, Column, Row, Message, Theme>
     where
         Theme: style::Catalog + container::Catalog,
     {
         Table {
-            header,
-            body,
-            footer: None,
+            header: true,
+            footer: false,
+            id: None,
             columns,
-            rows,
-            on_sync,
-            on_column_drag: None,
-            on_column_release: None,
+            rows: Rows::Slice(rows),
+            on_scroll: None,
+            on_column_resize: None,
+            on_column_autofit: None,
+            on_column_reset: None,
+            on_column_copied: None,
+            on_header_right_click: None,
+            on_render_stats: None,
+            on_columns_selected: None,
+            on_sort: None,
+            on_sort_append: None,
+            on_column_reorder: None,
+            on_row_reorder: None,
+            on_row_click: None,
+            on_row_double_click: None,
+            on_cell_click: None,
+            on_row_hover: None,
+            on_scroll_near_end: None,
+            on_rows_visible: None,
+            follow: false,
+            scroll_overflow_only: false,
+            row_key: None,
+            anchor_rows: false,
+            focused_cell: None,
+            on_cell_focus: None,
+            editing_cell: None,
+            on_cell_edit: None,
+            selected_rows: &[],
+            pinned_rows_top: &[],
+            pinned_rows_bottom: &[],
+            row_style: None,
+            row_kind: None,
+            failed_rows: &[],
+            on_retry: None,
+            same_group: None,
+            grand_total: false,
+            loading_rows: 0,
+            is_loaded: None,
+            loading_more: false,
+            loading_more_label: Cow::Borrowed("Loading…"),
+            row_height: None,
+            virtualized: false,
+            tree: None,
+            group_by: None,
+            filter_query: "",
+            filter: None,
+            overscan: 4,
+            frozen_columns: 0,
             min_width: 0.0,
+            width: Length::Fill,
+            height: Length::Fill,
+            max_body_height: None,
             min_column_width: 4.0,
             divider_width: 2.0,
+            divider_hit_area: 5.0,
+            cell_spacing: None,
+            resize_step: None,
+            resize_mode: ResizeMode::Column,
+            resize_animation: None,
             cell_padding: 4.into(),
-            style: Default::default(),
+            clip_cells: false,
+            gridlines: false,
+            align_y: alignment::Vertical::Top,
+            tooltip_position: tooltip::Position::FollowCursor,
+            tooltip_delay: Duration::from_millis(500),
+            class: Rc::new(<Theme as style::Catalog>::default()),
             scrollbar: scrollable::Scrollbar::default(),
+            minimize_overdraw: cfg!(target_arch = "wasm32"),
+            band_generation: None,
+            debug_overlay: false,
+        }
+    }
+
+    /// Creates a new [`Table`] with the provided [`Column`] definitions and `row_count`
+    /// rows, each fetched by its index with `row` as the table is built.
+    ///
+    /// This suits data sources which don't expose their rows as a whole, such as a paged
+    /// cache. Since there is no slice of rows to pass to [`Column::footer`], the footer is
+    /// built by [`Column::displayed_footer`] alone.
+    pub fn table_with<'a, Column, Row, Message, Theme>(
+        columns: &'a [Column],
+        row_count: usize,
+        row: impl Fn(usize) -> &'a Row + 'a,
+    ) -> Table<'a, Column, Row, Message, Theme>
+    where
+        Theme: style::Catalog + container::Catalog,
+    {
+        Table {
+            rows: Rows::Fetched {
+                count: row_count,
+                fetch: Rc::new(row),
+            },
+            ..table(columns, &[])
+        }
+    }
+
+    /// Creates a new [`Table`] with the provided [`Column`] definitions and the rows of an
+    /// iterator of references, such as the values of a map or a window of a collection.
+    ///
+    /// The references are collected once as the table is created, so the rows are reached
+    /// by index without walking the iterator again.
+    ///
+    /// As with [`table_with`], the footer is built by [`Column::displayed_footer`] alone.
+    pub fn table_iter<'a, Column, Row, Message, Theme, Rows>(
+        columns: &'a [Column],
+        rows: Rows,
+    ) -> Table<'a, Column, Row, Message, Theme>
+    where
+        Theme: style::Catalog + container::Catalog,
+        Rows: IntoIterator<Item = &'a Row>,
+    {
+        let rows: Vec<&'a Row> = rows.into_iter().collect();
+
+        // The indices of the table are within the rows it counts
+        table_with(columns, rows.len(), move |index| rows[index])
+    }
+
+    /// Creates a new [`Table`] with the provided [`Column`] definitions and the rows of a
+    /// [`Paged`] cache, fetched from a [`DataSource`](crate::paged::DataSource).
+    ///
+    /// The rows which aren't loaded yet are displayed as placeholders. Paired with
+    /// [`Table::virtualize`], only the visible rows are built, so the source can hold
+    /// millions of rows. See the [`paged`](crate::paged) module for how to fetch the pages
+    /// as the body is scrolled.
+    pub fn table_paged<'a, Column, Row, Message, Theme>(
+        columns: &'a [Column],
+        paged: &'a Paged<Row>,
+    ) -> Table<'a, Column, Row, Message, Theme>
+    where
+        Theme: style::Catalog + container::Catalog,
+    {
+        Table {
+            is_loaded: Some(Box::new(|index| paged.is_loaded(index))),
+            ..table_with(columns, paged.len(), |index| paged.row(index))
         }
     }
 
@@ -64,6 +309,64 @@ pub mod table {
             row: &'a Self::Row,
         ) -> Element<'a, Message, Theme, Renderer>;
 
+        /// Define the editor [`Element`] shown in place of the cell while it is edited, such as
+        /// a text input.
+        ///
+        /// Only the cells of [editable](Column::is_editable) columns are edited. By default,
+        /// this is the [`Column::cell`].
+        fn edit_cell(
+            &'a self,
+            col_index: usize,
+            row_index: usize,
+            row: &'a Self::Row,
+        ) -> Element<'a, Message, Theme, Renderer> {
+            self.cell(col_index, row_index, row)
+        }
+
+        /// Define the tooltip [`Element`] shown when hovering a cell of this column.
+        ///
+        /// The [`Table`] shows it after the [`tooltip_delay`](Table::tooltip_delay) has
+        /// elapsed and places it according to the [`tooltip_position`](Table::tooltip_position).
+        fn cell_tooltip(
+            &'a self,
+            _col_index: usize,
+            _row_index: usize,
+            _row: &'a Self::Row,
+        ) -> Option<Element<'a, Message, Theme, Renderer>> {
+            None
+        }
+
+        /// Define the tooltip [`Element`] shown when hovering the header of this column,
+        /// such as to explain an abbreviated title.
+        ///
+        /// It is shown like the [`cell_tooltip`](Column::cell_tooltip)s.
+        fn header_tooltip(
+            &'a self,
+            _col_index: usize,
+        ) -> Option<Element<'a, Message, Theme, Renderer>> {
+            None
+        }
+
+        /// Return the number of displayed columns spanned by the cell of the row, starting
+        /// from this column.
+        ///
+        /// The cells of the following columns it spans are skipped for the row, which is
+        /// useful for section rows or wide messages.
+        fn cell_span(&self, _col_index: usize, _row_index: usize, _row: &Self::Row) -> usize {
+            1
+        }
+
+        /// Return the [`Style`](container::Style) of the cell of the row, painted over the
+        /// row background, to tint individual cells.
+        fn cell_style(
+            &self,
+            _col_index: usize,
+            _row_index: usize,
+            _row: &Self::Row,
+        ) -> Option<container::Style> {
+            None
+        }
+
         /// Define the footer [`Element`] for this column.
         fn footer(
             &'a self,
@@ -73,11 +376,434 @@ pub mod table {
             None
         }
 
+        /// Define the footer [`Element`] for this column from the rows currently displayed
+        /// by the [`Table`].
+        ///
+        /// This is useful for totals which should reflect what the user sees. By default,
+        /// this returns [`Column::footer`] for the full set of rows, if the [`Table`] was
+        /// created from a slice of rows with [`table`].
+        fn displayed_footer(
+            &'a self,
+            col_index: usize,
+            displayed: Displayed<'a, '_, Self::Row>,
+        ) -> Option<Element<'a, Message, Theme, Renderer>> {
+            match displayed.rows {
+                Rows::Slice(rows) => self.footer(col_index, rows),
+                Rows::Fetched { .. } => None,
+            }
+        }
+
+        /// Define the summary [`Element`] for this column over the given rows, shown in the
+        /// band of the given [`SummaryLevel`].
+        ///
+        /// Summary bands are enabled with [`Table::subtotals`] and [`Table::grand_total`].
+        fn summary(
+            &'a self,
+            _col_index: usize,
+            _level: SummaryLevel,
+            _rows: Displayed<'a, '_, Self::Row>,
+        ) -> Option<Element<'a, Message, Theme, Renderer>> {
+            None
+        }
+
+        /// Define the header [`Element`] of a group of rows for this column, given all the
+        /// rows of the group, including the collapsed ones.
+        ///
+        /// Groups of rows are enabled with [`Table::group_by`].
+        fn group_header(
+            &'a self,
+            _col_index: usize,
+            _rows: Displayed<'a, '_, Self::Row>,
+        ) -> Option<Element<'a, Message, Theme, Renderer>> {
+            None
+        }
+
+        /// Return the text of the cell, as copied by
+        /// [`operation::copy_column`](crate::operation::copy_column) and with the
+        /// [`selected_rows`](Table::selected_rows).
+        fn cell_text(
+            &self,
+            _col_index: usize,
+            _row_index: usize,
+            _row: &Self::Row,
+        ) -> Option<String> {
+            None
+        }
+
+        /// Return the vertical alignment of the cells of this column, overriding the
+        /// [`Table`] wide [`align_y`](Table::align_y).
+        fn align_y(&self) -> Option<alignment::Vertical> {
+            None
+        }
+
+        /// Return whether this column is selected, as reported by
+        /// [`Table::on_columns_selected`].
+        fn is_selected(&self) -> bool {
+            false
+        }
+
+        /// Return the label of the group of this column, if any.
+        ///
+        /// Adjacent displayed columns in the same group share a header spanning them, shown
+        /// above their own headers.
+        fn group(&self) -> Option<&str> {
+            None
+        }
+
+        /// Return whether this column is displayed.
+        ///
+        /// Hidden columns keep their index, and their width & resize offset are left as
+        /// they are, so they are restored as they were once displayed again.
+        fn is_visible(&self) -> bool {
+            true
+        }
+
+        /// Return whether the cells of this column can be edited, see
+        /// [`Table::on_cell_edit`].
+        fn is_editable(&self) -> bool {
+            false
+        }
+
+        /// Return whether the cells of this column are plain text, drawn directly from their
+        /// [`cell_text`](Self::cell_text) instead of building their [`cell`](Self::cell).
+        ///
+        /// This keeps huge tables fast to build & draw. The cells which are interactive or
+        /// styled, such as focused, edited, clicked, styled with
+        /// [`cell_style`](Self::cell_style) or with a [`cell_tooltip`](Self::cell_tooltip),
+        /// are still built with [`cell`](Self::cell).
+        fn is_plain(&self) -> bool {
+            false
+        }
+
+        /// Return the order this column is sorted in, if any, as reported by
+        /// [`Table::on_sort`].
+        ///
+        /// The header of a sorted column shows an indicator of the order.
+        fn sort_order(&self) -> Option<SortOrder> {
+            None
+        }
+
+        /// Return the priority of this column among the columns the rows are sorted by,
+        /// starting from 1, such as the [`SortModel::rank`].
+        ///
+        /// The header of a sorted column shows it next to the indicator of the order.
+        fn sort_rank(&self) -> Option<usize> {
+            None
+        }
+
+        /// Return the minimum width this column can be resized to, overriding the [`Table`]
+        /// wide [`min_column_width`](Table::min_column_width).
+        fn min_width(&self) -> Option<f32> {
+            None
+        }
+
+        /// Return the maximum width this column can be resized to.
+        fn max_width(&self) -> Option<f32> {
+            None
+        }
+
+        /// Return how the width of this column is determined.
+        ///
+        /// By default, this is the [`Column::width`].
+        fn sizing(&self) -> ColumnWidth {
+            ColumnWidth::Fixed(self.width())
+        }
+
+        /// Return the width this column is reset to with the divider, see
+        /// [`Table::on_column_reset`].
+        fn default_width(&self) -> Option<f32> {
+            None
+        }
+
         /// Return the fixed width for this column.
         fn width(&self) -> f32;
+    }
 
-        /// Return the offset of an on-going resize of this column.
-        fn resize_offset(&self) -> Option<f32>;
+    /// How the width of a column is determined, returned by [`Column::sizing`].
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum ColumnWidth {
+        /// A fixed width.
+        Fixed(f32),
+        /// A share of the width left by the other columns up to the
+        /// [`min_width`](Table::min_width) of the [`Table`], proportional to the given portion.
+        FillPortion(u16),
+        /// The minimum width of the column, see [`Column::min_width`].
+        Shrink,
+    }
+
+    /// How dragging a divider resizes the columns, see [`Table::resize_mode`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum ResizeMode {
+        /// Only the column before the divider is resized, changing the width of the table.
+        #[default]
+        Column,
+        /// The column after the divider is resized by the opposite amount, keeping the width
+        /// of the table. [`Table::on_column_resize`] is produced for both columns.
+        ///
+        /// The divider of the last displayed column resizes it alone.
+        Neighbor,
+    }
+
+    /// How a row is displayed, see [`Table::row_kind`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum RowKind {
+        /// A cell for each displayed column.
+        #[default]
+        Cells,
+        /// A single element spanning the full width of the table, such as a separator or a
+        /// banner: the [`Column::cell`] of the first displayed column, styled with
+        /// [`Status::FullWidthRow`](style::Status::FullWidthRow).
+        ///
+        /// The row can't be hovered nor selected.
+        FullWidth,
+    }
+
+    /// The order of a sorted column, returned by [`Column::sort_order`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum SortOrder {
+        /// From the smallest value to the largest.
+        Ascending,
+        /// From the largest value to the smallest.
+        Descending,
+    }
+
+    impl SortOrder {
+        /// Returns the order following the `current` one when a header is clicked, cycling
+        /// through [`SortOrder::Ascending`], [`SortOrder::Descending`] and unsorted.
+        pub fn cycle(current: Option<Self>) -> Option<Self> {
+            match current {
+                None => Some(SortOrder::Ascending),
+                Some(SortOrder::Ascending) => Some(SortOrder::Descending),
+                Some(SortOrder::Descending) => None,
+            }
+        }
+
+        // The arrow shown in the header of a column sorted in this order
+        pub(crate) fn indicator(self) -> &'static str {
+            match self {
+                SortOrder::Ascending => "▲",
+                SortOrder::Descending => "▼",
+            }
+        }
+    }
+
+    /// A change to the edited cell, produced by [`Table::on_cell_edit`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum CellEdit {
+        /// The cell should be edited.
+        Start {
+            /// The index of the row of the cell.
+            row: usize,
+            /// The index of the column of the cell.
+            column: usize,
+        },
+        /// The edit of the cell should be applied.
+        Commit {
+            /// The index of the row of the cell.
+            row: usize,
+            /// The index of the column of the cell.
+            column: usize,
+        },
+        /// The edit of the cell should be discarded.
+        Cancel {
+            /// The index of the row of the cell.
+            row: usize,
+            /// The index of the column of the cell.
+            column: usize,
+        },
+    }
+
+    /// The identifier of a [`Table`], to target it with an [`operation`](crate::operation).
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct Id(pub(crate) widget::Id);
+
+    impl Id {
+        /// Creates a custom [`Id`].
+        pub fn new(id: impl Into<Cow<'static, str>>) -> Self {
+            Self(widget::Id::new(id))
+        }
+
+        /// Creates a unique [`Id`].
+        ///
+        /// This function produces a different [`Id`] every time it is called.
+        pub fn unique() -> Self {
+            Self(widget::Id::unique())
+        }
+    }
+
+    impl From<Id> for widget::Id {
+        fn from(id: Id) -> Self {
+            id.0
+        }
+    }
+
+    /// The level of a summary band, passed to [`Column::summary`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum SummaryLevel {
+        /// The band following each group of rows, enabled with [`Table::subtotals`].
+        Subtotal,
+        /// The band following all the rows, enabled with [`Table::grand_total`].
+        GrandTotal,
+    }
+
+    /// The rows currently displayed by a [`Table`], passed to [`Column::displayed_footer`].
+    #[derive(Debug)]
+    pub struct Displayed<'a, 'b, Row> {
+        rows: &'b Rows<'a, Row>,
+        indices: &'b [usize],
+    }
+
+    impl<'a, 'b, Row> Clone for Displayed<'a, 'b, Row> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<'a, 'b, Row> Copy for Displayed<'a, 'b, Row> {}
+
+    impl<'a: 'b, 'b, Row> Displayed<'a, 'b, Row> {
+        /// Returns an iterator over the displayed rows, in display order.
+        pub fn iter(&self) -> impl Iterator<Item = &'a Row> + 'b {
+            let rows = self.rows;
+
+            self.indices.iter().map(move |index| rows.get(*index))
+        }
+
+        /// Returns the indices of the displayed rows within [`all`](Self::all).
+        pub fn indices(&self) -> &'b [usize] {
+            self.indices
+        }
+
+        /// Returns the number of displayed rows.
+        pub fn len(&self) -> usize {
+            self.indices.len()
+        }
+
+        /// Returns `true` if no rows are displayed.
+        pub fn is_empty(&self) -> bool {
+            self.indices.is_empty()
+        }
+
+        /// Returns an iterator over the full set of rows, including those not displayed.
+        pub fn all(&self) -> impl Iterator<Item = &'a Row> + 'b {
+            let rows = self.rows;
+
+            (0..rows.len()).map(move |index| rows.get(index))
+        }
+    }
+
+    /// Statistics about building a [`Table`], reported by
+    /// [`on_render_stats`](Table::on_render_stats).
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Stats {
+        /// The number of rows built, full-width rows included.
+        pub rows: usize,
+        /// The number of cells built, a cell spanning several columns or a full-width row
+        /// counting as one.
+        pub cells: usize,
+        /// The time spent building the [`Table`].
+        pub elapsed: Duration,
+    }
+
+    // The handler of the resize of a column, shared by the dividers of the column & its
+    // neighbor
+    type OnResize<'a, Message> = Rc<dyn Fn(usize, f32) -> Message + 'a>;
+
+    // The handler of the right click of a header, along with where the table was clicked
+    type OnRightClick<'a, Message> = (Rc<dyn Fn(usize, Point) -> Message + 'a>, RightPress);
+
+    // A function of a row, such as its key
+    type RowFn<'a, Row, T> = Rc<dyn Fn(&Row) -> T + 'a>;
+
+    // A function of a row & its index, such as its style
+    type IndexedRowFn<'a, Row, T> = Rc<dyn Fn(usize, &Row) -> T + 'a>;
+
+    // Whether two adjacent rows belong to the same group
+    type SameGroupFn<'a, Row> = Rc<dyn Fn(&Row, &Row) -> bool + 'a>;
+
+    // Whether a row matches the query of the quick filter
+    type FilterFn<'a, Row> = Rc<dyn Fn(&Row, &str) -> bool + 'a>;
+
+    // The "depth", "is_expanded" & "on_toggle" functions of a tree
+    type TreeFns<'a, Row, Message> = (
+        RowFn<'a, Row, usize>,
+        RowFn<'a, Row, bool>,
+        Rc<dyn Fn(usize, bool) -> Message + 'a>,
+    );
+
+    // The "same_group", "is_expanded" & "on_toggle" functions of the groups of rows
+    type GroupFns<'a, Row, Message> = (
+        SameGroupFn<'a, Row>,
+        RowFn<'a, Row, bool>,
+        Rc<dyn Fn(usize, bool) -> Message + 'a>,
+    );
+
+    // The rows of a table, either collected beforehand or fetched by index
+    enum Rows<'a, Row> {
+        Slice(&'a [Row]),
+        Fetched {
+            count: usize,
+            fetch: Rc<dyn Fn(usize) -> &'a Row + 'a>,
+        },
+    }
+
+    impl<'a, Row> Clone for Rows<'a, Row> {
+        fn clone(&self) -> Self {
+            match self {
+                Self::Slice(rows) => Self::Slice(rows),
+                Self::Fetched { count, fetch } => Self::Fetched {
+                    count: *count,
+                    fetch: fetch.clone(),
+                },
+            }
+        }
+    }
+
+    impl<'a, Row> fmt::Debug for Rows<'a, Row>
+    where
+        Row: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Slice(rows) => f.debug_list().entries(rows.iter()).finish(),
+                Self::Fetched { count, .. } => {
+                    f.debug_struct("Fetched").field("count", count).finish()
+                }
+            }
+        }
+    }
+
+    impl<'a, Row> Rows<'a, Row> {
+        fn len(&self) -> usize {
+            match self {
+                Self::Slice(rows) => rows.len(),
+                Self::Fetched { count, .. } => *count,
+            }
+        }
+
+        fn get(&self, index: usize) -> &'a Row {
+            match self {
+                Self::Slice(rows) => &rows[index],
+                Self::Fetched { fetch, .. } => (fetch)(index),
+            }
+        }
+    }
+
+    // A group of consecutive displayed rows
+    struct RowGroup {
+        // The indices of all the rows of the group
+        rows: Vec<usize>,
+        // The position of the first row of the group among the displayed rows
+        start: usize,
+        expanded: bool,
+    }
+
+    // The key of a child of the body, by the key of its row if any, or else by its position
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum BodyKey {
+        Row(u64),
+        Position(usize),
     }
 
     /// An element to display rows of data into columns.
@@ -86,146 +812,1179 @@ pub mod table {
     where
         Theme: style::Catalog + container::Catalog,
     {
-        header: scrollable::Id,
-        body: scrollable::Id,
-        footer: Option<scrollable::Id>,
+        header: bool,
+        footer: bool,
+        id: Option<Id>,
         columns: &'a [Column],
-        rows: &'a [Row],
-        on_sync: fn(scrollable::AbsoluteOffset) -> Message,
-        on_column_drag: Option<fn(usize, f32) -> Message>,
-        on_column_release: Option<Message>,
+        rows: Rows<'a, Row>,
+        on_scroll: Option<Rc<dyn Fn(scrollable::Viewport) -> Message + 'a>>,
+        on_column_resize: Option<OnResize<'a, Message>>,
+        on_column_autofit: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+        on_column_reset: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+        on_column_copied: Option<Box<dyn Fn(usize, String) -> Message + 'a>>,
+        on_header_right_click: Option<Rc<dyn Fn(usize, Point) -> Message + 'a>>,
+        on_render_stats: Option<Rc<dyn Fn(Stats) -> Message + 'a>>,
+        on_columns_selected: Option<Rc<dyn Fn(Vec<usize>) -> Message + 'a>>,
+        on_sort: Option<Rc<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+        on_sort_append: Option<Rc<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+        on_column_reorder: Option<Rc<dyn Fn(usize, usize) -> Message + 'a>>,
+        on_row_reorder: Option<Rc<dyn Fn(usize, usize) -> Message + 'a>>,
+        on_row_click: Option<Rc<dyn Fn(usize) -> Message + 'a>>,
+        on_row_double_click: Option<Rc<dyn Fn(usize) -> Message + 'a>>,
+        on_cell_click: Option<Rc<dyn Fn(usize, usize) -> Message + 'a>>,
+        on_row_hover: Option<Rc<dyn Fn(Option<usize>) -> Message + 'a>>,
+        on_scroll_near_end: Option<(f32, Message)>,
+        on_rows_visible: Option<Rc<dyn Fn(Range<usize>) -> Message + 'a>>,
+        follow: bool,
+        scroll_overflow_only: bool,
+        row_key: Option<RowFn<'a, Row, u64>>,
+        anchor_rows: bool,
+        focused_cell: Option<(usize, usize)>,
+        on_cell_focus: Option<Rc<dyn Fn(usize, usize) -> Message + 'a>>,
+        editing_cell: Option<(usize, usize)>,
+        on_cell_edit: Option<Rc<dyn Fn(CellEdit) -> Message + 'a>>,
+        selected_rows: &'a [usize],
+        pinned_rows_top: &'a [usize],
+        pinned_rows_bottom: &'a [usize],
+        row_style: Option<IndexedRowFn<'a, Row, RowStyle>>,
+        row_kind: Option<IndexedRowFn<'a, Row, RowKind>>,
+        failed_rows: &'a [Range<usize>],
+        on_retry: Option<Rc<dyn Fn(Range<usize>) -> Message + 'a>>,
+        same_group: Option<SameGroupFn<'a, Row>>,
+        grand_total: bool,
+        loading_rows: usize,
+        is_loaded: Option<Box<dyn Fn(usize) -> bool + 'a>>,
+        loading_more: bool,
+        loading_more_label: Cow<'a, str>,
+        row_height: Option<IndexedRowFn<'a, Row, f32>>,
+        virtualized: bool,
+        tree: Option<TreeFns<'a, Row, Message>>,
+        group_by: Option<GroupFns<'a, Row, Message>>,
+        filter_query: &'a str,
+        filter: Option<FilterFn<'a, Row>>,
+        overscan: usize,
+        frozen_columns: usize,
         min_width: f32,
+        width: Length,
+        height: Length,
+        max_body_height: Option<f32>,
         min_column_width: f32,
         divider_width: f32,
+        divider_hit_area: f32,
+        cell_spacing: Option<f32>,
+        resize_step: Option<f32>,
+        resize_mode: ResizeMode,
+        resize_animation: Option<Duration>,
         cell_padding: Padding,
-        style: <Theme as style::Catalog>::Style,
+        clip_cells: bool,
+        gridlines: bool,
+        align_y: alignment::Vertical,
+        tooltip_position: tooltip::Position,
+        tooltip_delay: Duration,
+        class: style::Shared<'a, Theme>,
         scrollbar: scrollable::Scrollbar,
+        minimize_overdraw: bool,
+        band_generation: Option<u64>,
+        debug_overlay: bool,
     }
 
     impl<'a, Column, Row, Message, Theme> Table<'a, Column, Row, Message, Theme>
     where
         Theme: style::Catalog + container::Catalog,
     {
-        /// Sets the message that will be produced when a [`Column`] is resizing. Setting this
-        /// will enable the resizing interaction.
-        ///
-        /// `on_drag` will emit a message during an on-going resize. It is up to the consumer to return
-        /// this value for the associated column in [`Column::resize_offset`].
+        /// Sets the message that will be produced when the body is scrolled, with its
+        /// [`Viewport`](scrollable::Viewport).
         ///
-        /// `on_release` is emited when the resize is finished. It is up to the consumer to apply the last
-        /// `on_drag` offset to the column's stored width.
-        pub fn on_column_resize(
-            self,
-            on_drag: fn(usize, f32) -> Message,
-            on_release: Message,
-        ) -> Self {
+        /// The closure can capture what identifies the [`Table`], such as when several
+        /// tables share the same message.
+        pub fn on_scroll(self, on_scroll: impl Fn(scrollable::Viewport) -> Message + 'a) -> Self {
             Self {
-                on_column_drag: Some(on_drag),
-                on_column_release: Some(on_release),
+                on_scroll: Some(Rc::new(on_scroll)),
                 ..self
             }
         }
 
-        /// Show the footer returned by [`Column::footer`].
-        pub fn footer(self, footer: scrollable::Id) -> Self {
+        /// Sets the message that will be produced once the body is scrolled within
+        /// `threshold` of its bottom, such as to load the next page of rows.
+        ///
+        /// The message isn't produced again until the body is scrolled away from its bottom,
+        /// or rows are appended past the threshold.
+        pub fn on_scroll_near_end(self, threshold: f32, message: Message) -> Self {
             Self {
-                footer: Some(footer),
+                on_scroll_near_end: Some((threshold, message)),
                 ..self
             }
         }
 
-        /// Sets the minimum width of table.
+        /// Sets whether the body follows its end, such as for a log, keeping the last row
+        /// visible as rows are appended.
         ///
-        /// This is useful to use in conjuction with [`responsive`](iced_widget::responsive) to ensure
-        /// the table always fills the width of it's parent container.
-        pub fn min_width(self, min_width: f32) -> Self {
-            Self { min_width, ..self }
+        /// Following pauses once the body is scrolled up, and resumes once it is scrolled
+        /// back to its end.
+        pub fn follow(self, follow: bool) -> Self {
+            Self { follow, ..self }
         }
 
-        /// Sets the minimum width a column can be resized to.
-        pub fn min_column_width(self, min_column_width: f32) -> Self {
+        /// Sets the key identifying each row, which stays the same as rows are added, removed or
+        /// reordered, such as the id of the record it displays.
+        ///
+        /// The widgets of the cells of a row, such as text inputs & scrollables, then keep their
+        /// state as the row moves, once sorted, filtered or as other rows are inserted or
+        /// deleted. Keys should be unique among the displayed rows.
+        pub fn row_key(self, row_key: impl Fn(&Row) -> u64 + 'a) -> Self {
             Self {
-                min_column_width,
+                row_key: Some(Rc::new(row_key)),
                 ..self
             }
         }
 
-        /// Sets the width of the column dividers.
-        pub fn divider_width(self, divider_width: f32) -> Self {
+        /// Sets whether the body is kept scrolled to the same rows, as rows are added or
+        /// removed above them, such as for a live feed. The rows are identified by their
+        /// [`row_key`](Self::row_key), without which this has no effect.
+        pub fn anchor_rows(self, anchor_rows: bool) -> Self {
             Self {
-                divider_width,
+                anchor_rows,
                 ..self
             }
         }
 
-        /// Sets the [`Padding`] used inside each cell of the [`Table`].
-        pub fn cell_padding(self, cell_padding: impl Into<Padding>) -> Self {
+        /// Sets the message that will be produced when a [`Column`] is resized, with its new
+        /// width. Setting this will enable the resizing interaction.
+        ///
+        /// The [`Table`] resizes the column while its divider is dragged, and only emits the
+        /// message once the divider is released. It is up to the consumer to store the new
+        /// width of the column.
+        pub fn on_column_resize(self, on_resize: impl Fn(usize, f32) -> Message + 'a) -> Self {
             Self {
-                cell_padding: cell_padding.into(),
+                on_column_resize: Some(Rc::new(on_resize)),
                 ..self
             }
         }
 
-        /// Sets the style variant of this [`Table`].
-        pub fn style(self, style: impl Into<<Theme as style::Catalog>::Style>) -> Self {
+        /// Sets the message that will be produced when the divider of a column is
+        /// double-clicked, to fit the width of the column to its content.
+        ///
+        /// It is up to the consumer to update the column's stored width, such as with the
+        /// width measured by [`operation::fit_columns`](crate::operation::fit_columns). This
+        /// only applies to resizable columns, see [`Table::on_column_resize`].
+        pub fn on_column_autofit(self, on_autofit: impl Fn(usize) -> Message + 'a) -> Self {
             Self {
-                style: style.into(),
+                on_column_autofit: Some(Box::new(on_autofit)),
                 ..self
             }
         }
 
-        ///  Sets the [`Scrollbar`](iced_widget::scrollable::Scrollbar) used for the table's body scrollable.
-        pub fn scrollbar(self, scrollbar: scrollable::Scrollbar) -> Self {
-            Self { scrollbar, ..self }
+        /// Sets the message that will be produced when the divider of a column is clicked
+        /// while holding Ctrl (or Cmd on macOS), to reset the column to its default width.
+        ///
+        /// Without it, the gesture resizes the columns returning a [`Column::default_width`]
+        /// to that width through [`Table::on_column_resize`].
+        pub fn on_column_reset(self, on_reset: impl Fn(usize) -> Message + 'a) -> Self {
+            Self {
+                on_column_reset: Some(Box::new(on_reset)),
+                ..self
+            }
         }
-    }
-
-    impl<'a, Column, Row, Message, Theme, Renderer> From<Table<'a, Column, Row, Message, Theme>>
-        for Element<'a, Message, Theme, Renderer>
-    where
-        Renderer: iced_core::Renderer + 'a,
-        Theme: style::Catalog + container::Catalog + scrollable::Catalog + 'a,
-        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
-        Message: 'a + Clone,
-    {
-        fn from(table: Table<'a, Column, Row, Message, Theme>) -> Self {
-            let Table {
-                header,
-                body,
-                footer,
-                columns,
-                rows,
-                on_sync,
-                on_column_drag,
-                on_column_release,
-                min_width,
-                min_column_width,
-                divider_width,
-                cell_padding,
-                style,
-                scrollbar,
-            } = table;
 
-            let header = scrollable(style::wrapper::header(
-                row(columns
-                    .iter()
-                    .enumerate()
-                    .map(|(index, column)| {
-                        header_container(
-                            index,
-                            column,
-                            on_column_drag,
-                            on_column_release.clone(),
-                            min_column_width,
-                            divider_width,
-                            cell_padding,
-                            style.clone(),
-                        )
-                    })
-                    .chain(dummy_container(columns, min_width, min_column_width))),
-                style.clone(),
-            ))
-            .id(header)
+        /// Sets the message that will be produced with the index & text of a column once
+        /// copied to the clipboard by [`operation::copy_column`](crate::operation::copy_column).
+        ///
+        /// This only applies to a table with an [`id`](Table::id).
+        pub fn on_column_copied(self, on_copied: impl Fn(usize, String) -> Message + 'a) -> Self {
+            Self {
+                on_column_copied: Some(Box::new(on_copied)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when the header of a column is
+        /// right-clicked, such as to show a menu for the column.
+        ///
+        /// `on_right_click` receives the index of the column and the position of the cursor,
+        /// relative to the top-left corner of the [`Table`].
+        pub fn on_header_right_click(
+            self,
+            on_right_click: impl Fn(usize, Point) -> Message + 'a,
+        ) -> Self {
+            Self {
+                on_header_right_click: Some(Rc::new(on_right_click)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when the selected columns change. Setting this
+        /// will enable selecting columns by clicking their header.
+        ///
+        /// Clicking a header selects its column only, while clicking with Ctrl (or Cmd on macOS)
+        /// held toggles it in the selection. It is up to the consumer to store the selection and
+        /// return it in [`Column::is_selected`].
+        ///
+        /// Along with [`on_sort`](Self::on_sort), plain clicks sort the column instead, and
+        /// columns are only selected by clicking with Ctrl (or Cmd on macOS) held.
+        pub fn on_columns_selected(
+            self,
+            on_columns_selected: impl Fn(Vec<usize>) -> Message + 'a,
+        ) -> Self {
+            Self {
+                on_columns_selected: Some(Rc::new(on_columns_selected)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when a header is clicked to sort its column.
+        /// Setting this will enable sorting by clicking headers.
+        ///
+        /// Clicking the header of a column sorted in [`SortOrder::Ascending`] order requests
+        /// [`SortOrder::Descending`], otherwise [`SortOrder::Ascending`]. It is up to the
+        /// consumer to sort the rows and return the order in [`Column::sort_order`].
+        ///
+        /// Clicks with Ctrl (or Cmd on macOS) held select the column instead, when
+        /// [`on_columns_selected`](Self::on_columns_selected) is set.
+        pub fn on_sort(self, on_sort: impl Fn(usize, SortOrder) -> Message + 'a) -> Self {
+            Self {
+                on_sort: Some(Rc::new(on_sort)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced instead of [`Table::on_sort`] when a
+        /// header is shift-clicked, to add its column as a secondary sort key, such as with
+        /// [`SortModel::append`].
+        pub fn on_sort_append(
+            self,
+            on_sort_append: impl Fn(usize, SortOrder) -> Message + 'a,
+        ) -> Self {
+            Self {
+                on_sort_append: Some(Rc::new(on_sort_append)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when a column is dragged by its header and
+        /// dropped at a new position. Setting this will enable reordering columns.
+        ///
+        /// `on_reorder` receives the index the column was dragged `from` and the index it
+        /// should be moved `to`, as in removing it from the columns and inserting it back. It
+        /// is up to the consumer to reorder the columns.
+        pub fn on_column_reorder(self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+            Self {
+                on_column_reorder: Some(Rc::new(on_reorder)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when a row is dragged and dropped at a new
+        /// position. Setting this will enable reordering rows.
+        ///
+        /// `on_reorder` receives the index the row was dragged `from` and the index it should
+        /// be moved `to`, as in removing it from the rows and inserting it back. It is up to
+        /// the consumer to reorder the rows.
+        pub fn on_row_reorder(self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+            Self {
+                on_row_reorder: Some(Rc::new(on_reorder)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when a row is clicked anywhere, including
+        /// its padding & empty cells, unless the content of a cell captures the click.
+        ///
+        /// `on_click` receives the index of the row.
+        pub fn on_row_click(self, on_click: impl Fn(usize) -> Message + 'a) -> Self {
+            Self {
+                on_row_click: Some(Rc::new(on_click)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when a row is double-clicked anywhere,
+        /// after the [`on_row_click`](Self::on_row_click) message of the second click.
+        ///
+        /// `on_double_click` receives the index of the row.
+        pub fn on_row_double_click(self, on_double_click: impl Fn(usize) -> Message + 'a) -> Self {
+            Self {
+                on_row_double_click: Some(Rc::new(on_double_click)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when a cell is clicked, unless its content
+        /// captures the click, before the [`on_row_click`](Self::on_row_click) message.
+        ///
+        /// `on_click` receives the index of the row and the index of the column.
+        pub fn on_cell_click(self, on_click: impl Fn(usize, usize) -> Message + 'a) -> Self {
+            Self {
+                on_cell_click: Some(Rc::new(on_click)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when the row under the cursor changes, such
+        /// as to show the details of the hovered row elsewhere.
+        ///
+        /// `on_hover` receives the index of the hovered row, or `None` once the cursor leaves
+        /// the rows.
+        pub fn on_row_hover(self, on_hover: impl Fn(Option<usize>) -> Message + 'a) -> Self {
+            Self {
+                on_row_hover: Some(Rc::new(on_hover)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when a cell is focused, with its row &
+        /// column indices. Setting this will enable keyboard navigation.
+        ///
+        /// Clicking a cell focuses it, and the arrow keys move the focus to the neighboring
+        /// cells of the [`focused_cell`](Self::focused_cell). It is up to the consumer to store
+        /// the focused cell.
+        pub fn on_cell_focus(self, on_cell_focus: impl Fn(usize, usize) -> Message + 'a) -> Self {
+            Self {
+                on_cell_focus: Some(Rc::new(on_cell_focus)),
+                ..self
+            }
+        }
+
+        /// Sets the focused cell, by its row & column indices, as reported by
+        /// [`on_cell_focus`](Self::on_cell_focus).
+        pub fn focused_cell(self, focused_cell: Option<(usize, usize)>) -> Self {
+            Self {
+                focused_cell,
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when the edited cell changes. Setting this
+        /// will enable editing the cells of [editable](Column::is_editable) columns.
+        ///
+        /// Double-clicking a cell starts editing it. While editing, Enter commits the edit,
+        /// Escape cancels it and Tab (or Shift+Tab) commits it and starts editing the next
+        /// (or previous) editable cell. It is up to the consumer to store the
+        /// [`editing_cell`](Self::editing_cell), apply or discard the edit, and focus the
+        /// editor returned by [`Column::edit_cell`].
+        pub fn on_cell_edit(self, on_cell_edit: impl Fn(CellEdit) -> Message + 'a) -> Self {
+            Self {
+                on_cell_edit: Some(Rc::new(on_cell_edit)),
+                ..self
+            }
+        }
+
+        /// Sets the edited cell, by its row & column indices, as reported by
+        /// [`on_cell_edit`](Self::on_cell_edit).
+        pub fn editing_cell(self, editing_cell: Option<(usize, usize)>) -> Self {
+            Self {
+                editing_cell,
+                ..self
+            }
+        }
+
+        /// Sets the [`Id`] of this [`Table`], which enables targeting it with an
+        /// [`operation`](crate::operation).
+        pub fn id(self, id: Id) -> Self {
+            Self {
+                id: Some(id),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced with the [`Stats`] of building this [`Table`].
+        ///
+        /// The message is produced once per view, with the next event processed by the
+        /// [`Table`] other than a redraw, so that reporting doesn't cause endless redraws.
+        /// When [virtualized](Table::virtualize), the rows built are the ones in view by then.
+        pub fn on_render_stats(self, on_render_stats: impl Fn(Stats) -> Message + 'a) -> Self {
+            Self {
+                on_render_stats: Some(Rc::new(on_render_stats)),
+                ..self
+            }
+        }
+
+        /// Sets the indices of the selected rows.
+        ///
+        /// While the cursor is over the [`Table`], Ctrl+C (or Cmd+C on macOS) copies the
+        /// displayed rows among them to the clipboard, one row per line and the text of each
+        /// column separated by tabs, as returned by [`Column::cell_text`].
+        ///
+        /// Selected rows are styled with the `selected` [`Status::Row`](style::Status::Row).
+        pub fn selected_rows(self, selected_rows: &'a [usize]) -> Self {
+            Self {
+                selected_rows,
+                ..self
+            }
+        }
+
+        /// Sets the indices of the rows pinned above the body, which stay visible while the
+        /// body scrolls vertically and scroll horizontally along with it.
+        ///
+        /// Pinned rows are still displayed in the body as well.
+        pub fn pin_rows_top(self, pinned_rows_top: &'a [usize]) -> Self {
+            Self {
+                pinned_rows_top,
+                ..self
+            }
+        }
+
+        /// Sets the indices of the rows pinned below the body, above the footer, which stay
+        /// visible while the body scrolls vertically and scroll horizontally along with it.
+        ///
+        /// Pinned rows are still displayed in the body as well.
+        pub fn pin_rows_bottom(self, pinned_rows_bottom: &'a [usize]) -> Self {
+            Self {
+                pinned_rows_bottom,
+                ..self
+            }
+        }
+
+        /// Sets the function returning the [`RowStyle`] of each row, given its index, so
+        /// that rows can be styled after their data instead of their index alone.
+        ///
+        /// [`RowStyle::Default`] rows keep the style of the [`Catalog`](crate::Catalog).
+        pub fn row_style(self, row_style: impl Fn(usize, &Row) -> RowStyle + 'a) -> Self {
+            Self {
+                row_style: Some(Rc::new(row_style)),
+                ..self
+            }
+        }
+
+        /// Sets the function returning the [`RowKind`] of each row, given its index, to
+        /// display some rows as a single element spanning all the columns.
+        pub fn row_kind(self, row_kind: impl Fn(usize, &Row) -> RowKind + 'a) -> Self {
+            Self {
+                row_kind: Some(Rc::new(row_kind)),
+                ..self
+            }
+        }
+
+        /// Marks ranges of rows which failed to load.
+        ///
+        /// Instead of its rows, each range is displayed as a single band with a retry button,
+        /// which produces the message returned by `on_retry` for that range.
+        pub fn failed_rows(
+            self,
+            failed_rows: &'a [Range<usize>],
+            on_retry: impl Fn(Range<usize>) -> Message + 'a,
+        ) -> Self {
+            Self {
+                failed_rows,
+                on_retry: Some(Rc::new(on_retry)),
+                ..self
+            }
+        }
+
+        /// Inserts a subtotal band after each group of consecutive displayed rows, where
+        /// `same_group` returns whether two adjacent rows belong to the same group.
+        ///
+        /// The cells of the band are returned by [`Column::summary`] with
+        /// [`SummaryLevel::Subtotal`] for the rows of the group.
+        pub fn subtotals(self, same_group: impl Fn(&Row, &Row) -> bool + 'a) -> Self {
+            Self {
+                same_group: Some(Rc::new(same_group)),
+                ..self
+            }
+        }
+
+        /// Sets whether a grand total band is appended after the last displayed row.
+        ///
+        /// The cells of the band are returned by [`Column::summary`] with
+        /// [`SummaryLevel::GrandTotal`] for all the displayed rows.
+        pub fn grand_total(self, grand_total: bool) -> Self {
+            Self {
+                grand_total,
+                ..self
+            }
+        }
+
+        /// Sets whether more rows are being loaded, such as when fetching the next page of an
+        /// infinitely scrolling [`Table`], in which case a row reading "Loading…" is appended
+        /// to the body.
+        pub fn loading_more(self, loading_more: bool) -> Self {
+            Self {
+                loading_more,
+                ..self
+            }
+        }
+
+        /// Appends `row_count` placeholder rows, sized by the column widths, such as while
+        /// the rows are fetched asynchronously. The placeholders shimmer while visible.
+        pub fn loading(self, row_count: usize) -> Self {
+            Self {
+                loading_rows: row_count,
+                ..self
+            }
+        }
+
+        /// Sets the text of the row appended while [`loading_more`](Self::loading_more).
+        pub fn loading_more_label(self, label: impl iced_core::text::IntoFragment<'a>) -> Self {
+            Self {
+                loading_more_label: label.into_fragment(),
+                ..self
+            }
+        }
+
+        /// Displays the rows as a tree, where each row is a child of the closest row before it
+        /// with a smaller `depth`, as when flattening the tree in depth-first order.
+        ///
+        /// The first column indents each row by its depth and shows a toggle in front of rows
+        /// with children, which produces the message returned by `on_toggle` with the index of
+        /// the row and whether it should be expanded. The children of rows for which
+        /// `is_expanded` returns `false` are hidden. It is up to the consumer to store whether
+        /// each row is expanded.
+        pub fn tree(
+            self,
+            depth: impl Fn(&Row) -> usize + 'a,
+            is_expanded: impl Fn(&Row) -> bool + 'a,
+            on_toggle: impl Fn(usize, bool) -> Message + 'a,
+        ) -> Self {
+            Self {
+                tree: Some((Rc::new(depth), Rc::new(is_expanded), Rc::new(on_toggle))),
+                ..self
+            }
+        }
+
+        /// Groups consecutive displayed rows, where `same_group` returns whether two adjacent
+        /// rows belong to the same group, and inserts a header band before each group.
+        ///
+        /// The cells of the band are returned by [`Column::group_header`], behind a toggle in
+        /// front of the first column which produces the message returned by `on_toggle` with
+        /// the index of the first row of the group and whether it should be expanded. The
+        /// rows of groups for which `is_expanded` returns `false`, given their first row, are
+        /// hidden. It is up to the consumer to store whether each group is expanded.
+        pub fn group_by(
+            self,
+            same_group: impl Fn(&Row, &Row) -> bool + 'a,
+            is_expanded: impl Fn(&Row) -> bool + 'a,
+            on_toggle: impl Fn(usize, bool) -> Message + 'a,
+        ) -> Self {
+            Self {
+                group_by: Some((
+                    Rc::new(same_group),
+                    Rc::new(is_expanded),
+                    Rc::new(on_toggle),
+                )),
+                ..self
+            }
+        }
+
+        /// Only displays the rows for which `matches` returns `true` given the `query`, such as
+        /// the content of a search field. An empty `query` displays all the rows.
+        ///
+        /// The hidden rows keep their index, so the indices passed to [`Column::cell`] and
+        /// messages still refer to the full set of rows.
+        pub fn quick_filter(
+            self,
+            query: &'a str,
+            matches: impl Fn(&Row, &str) -> bool + 'a,
+        ) -> Self {
+            Self {
+                filter_query: query,
+                filter: Some(Rc::new(matches)),
+                ..self
+            }
+        }
+
+        /// Sets the height of each row, returned by `row_height` for its index, instead of the
+        /// height of its tallest cell.
+        ///
+        /// The cells are laid out within that height and aligned according to
+        /// [`align_y`](Self::align_y), so taller rows can make room for wrapped text or
+        /// images while the others stay compact.
+        pub fn row_height(self, row_height: impl Fn(usize, &Row) -> f32 + 'a) -> Self {
+            Self {
+                row_height: Some(Rc::new(row_height)),
+                ..self
+            }
+        }
+
+        /// Only builds the rows visible in the body, given the height of each row returned by
+        /// `row_height` for its index, as with [`row_height`](Self::row_height). This keeps
+        /// the [`Table`] responsive with large numbers of rows.
+        ///
+        /// The rows are built as they are scrolled into view, or as the body is resized,
+        /// while the rows out of view only take up their height. The bands of groups,
+        /// summaries & rows which failed to load are still built along with the view, and
+        /// laid out with their own height.
+        pub fn virtualize(self, row_height: impl Fn(usize, &Row) -> f32 + 'a) -> Self {
+            Self {
+                row_height: Some(Rc::new(row_height)),
+                virtualized: true,
+                ..self
+            }
+        }
+
+        /// Sets how many rows are built beyond each edge of the visible part of a
+        /// [`virtualize`](Self::virtualize)d body, which defaults to 4.
+        ///
+        /// This hides blank space while scrolling quickly, before the next view is built.
+        pub fn overscan(self, overscan: usize) -> Self {
+            Self { overscan, ..self }
+        }
+
+        /// Sets the message produced with the range of the rows built by a
+        /// [`virtualize`](Self::virtualize)d body, including the [`overscan`](Self::overscan)
+        /// rows beyond each edge of the visible part, once it changes as the body is scrolled
+        /// or resized.
+        ///
+        /// The range can be [requested](crate::paged::Paged::request) from a
+        /// [`Paged`] cache, so the rows are fetched slightly ahead of being scrolled into
+        /// view.
+        pub fn on_rows_visible(
+            self,
+            on_rows_visible: impl Fn(Range<usize>) -> Message + 'a,
+        ) -> Self {
+            Self {
+                on_rows_visible: Some(Rc::new(on_rows_visible)),
+                ..self
+            }
+        }
+
+        /// Sets how many leading columns stay in place while the rest of the [`Table`] is
+        /// scrolled horizontally, such as a column identifying each row.
+        ///
+        /// The frozen columns scroll vertically along with the body, and cover the columns
+        /// scrolled under them.
+        pub fn freeze_columns(self, frozen_columns: usize) -> Self {
+            Self {
+                frozen_columns,
+                ..self
+            }
+        }
+
+        /// Show the header of the columns, which is shown by default.
+        ///
+        /// Without a header, the columns can only be resized from the
+        /// [`footer`](Self::footer), and can't be sorted nor reordered from the table.
+        pub fn header(self, header: bool) -> Self {
+            Self { header, ..self }
+        }
+
+        /// Show the footer returned by [`Column::displayed_footer`], which defaults to
+        /// [`Column::footer`].
+        pub fn footer(self, footer: bool) -> Self {
+            Self { footer, ..self }
+        }
+
+        /// Sets the minimum width of table.
+        ///
+        /// The width left by the columns is shared among the [`ColumnWidth::FillPortion`]
+        /// columns, if any.
+        ///
+        /// This is useful to use in conjuction with [`responsive`](iced_widget::responsive) to ensure
+        /// the table always fills the width of it's parent container.
+        pub fn min_width(self, min_width: f32) -> Self {
+            Self { min_width, ..self }
+        }
+
+        /// Sets the width of the [`Table`], which fills its parent by default.
+        pub fn width(self, width: impl Into<Length>) -> Self {
+            Self {
+                width: width.into(),
+                ..self
+            }
+        }
+
+        /// Sets the height of the [`Table`], which fills its parent by default.
+        pub fn height(self, height: impl Into<Length>) -> Self {
+            Self {
+                height: height.into(),
+                ..self
+            }
+        }
+
+        /// Shrinks the body to the height of its rows, only scrolling them once they exceed
+        /// `max_height`, instead of filling the height of the [`Table`].
+        ///
+        /// This is useful along with a [`height`](Self::height) of [`Length::Shrink`], so a
+        /// table with few rows doesn't take up the height of its parent.
+        pub fn max_body_height(self, max_height: f32) -> Self {
+            Self {
+                max_body_height: Some(max_height),
+                ..self
+            }
+        }
+
+        /// Sets the minimum width a column can be resized to, unless overridden by
+        /// [`Column::min_width`].
+        pub fn min_column_width(self, min_column_width: f32) -> Self {
+            Self {
+                min_column_width,
+                ..self
+            }
+        }
+
+        /// Sets the width of the column dividers.
+        pub fn divider_width(self, divider_width: f32) -> Self {
+            Self {
+                divider_width,
+                ..self
+            }
+        }
+
+        /// Sets how far the area grabbing a column divider extends on each side of it.
+        ///
+        /// Defaults to 5 pixels.
+        pub fn divider_hit_area(self, divider_hit_area: f32) -> Self {
+            Self {
+                divider_hit_area,
+                ..self
+            }
+        }
+
+        /// Sets the space left at the end of the cells without a divider, such as the cells of
+        /// the body, instead of the width of the dividers.
+        ///
+        /// Paired with a thin [`divider_width`](Self::divider_width) & a wide
+        /// [`divider_hit_area`](Self::divider_hit_area), the dividers of the header stay easy
+        /// to grab, without gaps as wide running through the rows.
+        pub fn cell_spacing(self, cell_spacing: f32) -> Self {
+            Self {
+                cell_spacing: Some(cell_spacing),
+                ..self
+            }
+        }
+
+        /// Sets the [`ResizeMode`] of the columns resized with the dividers.
+        pub fn resize_mode(self, resize_mode: ResizeMode) -> Self {
+            Self {
+                resize_mode,
+                ..self
+            }
+        }
+
+        /// Snaps the widths of the columns resized with the dividers to multiples of the
+        /// given step, such as 10 pixels.
+        pub fn resize_step(self, resize_step: f32) -> Self {
+            Self {
+                resize_step: Some(resize_step).filter(|step| *step > 0.0),
+                ..self
+            }
+        }
+
+        /// Animates the columns to their new width over the given duration, instead of
+        /// resizing them at once, when their width is changed by the consumer, such as
+        /// when fitting or resetting them, or applying a saved layout.
+        ///
+        /// The width a column is dragged to with its divider is applied at once.
+        pub fn animate_resize(self, duration: Duration) -> Self {
+            Self {
+                resize_animation: Some(duration),
+                ..self
+            }
+        }
+
+        /// Sets the [`Padding`] used inside each cell of the [`Table`].
+        pub fn cell_padding(self, cell_padding: impl Into<Padding>) -> Self {
+            Self {
+                cell_padding: cell_padding.into(),
+                ..self
+            }
+        }
+
+        /// Sets whether the content of each cell is clipped to the bounds of its column.
+        ///
+        /// This prevents wide content, such as images or long unbroken strings, from
+        /// being drawn over neighboring columns.
+        pub fn clip_cells(self, clip_cells: bool) -> Self {
+            Self { clip_cells, ..self }
+        }
+
+        /// Sets whether vertical gridlines are drawn between the columns of the body,
+        /// aligned to the dividers, as styled by [`Status::Gridline`](style::Status::Gridline).
+        pub fn gridlines(self, gridlines: bool) -> Self {
+            Self { gridlines, ..self }
+        }
+
+        /// Sets the vertical alignment of the content of each cell within its row.
+        ///
+        /// This can be overriden for a single column with [`Column::align_y`].
+        pub fn align_y(self, align_y: impl Into<alignment::Vertical>) -> Self {
+            Self {
+                align_y: align_y.into(),
+                ..self
+            }
+        }
+
+        /// Sets the [`Position`](iced_widget::tooltip::Position) of the tooltips returned
+        /// by [`Column::cell_tooltip`] & [`Column::header_tooltip`].
+        pub fn tooltip_position(self, tooltip_position: tooltip::Position) -> Self {
+            Self {
+                tooltip_position,
+                ..self
+            }
+        }
+
+        /// Sets how long a cell or header must be hovered before its tooltip is shown.
+        pub fn tooltip_delay(self, tooltip_delay: Duration) -> Self {
+            Self {
+                tooltip_delay,
+                ..self
+            }
+        }
+
+        /// Caches the layout of the header & footer across views for as long as the column
+        /// widths, the shape of the bands and the provided `generation` don't change.
+        ///
+        /// This keeps scrolling of wide tables smooth, since every view following an
+        /// [`on_scroll`](Self::on_scroll) message otherwise lays out the header & footer from
+        /// scratch. The `generation` must change along with the content of the header &
+        /// footer, such as their titles or footer totals, or stale layouts will be shown.
+        pub fn cache_bands(self, generation: impl Hash) -> Self {
+            let mut hasher = DefaultHasher::new();
+            generation.hash(&mut hasher);
+
+            Self {
+                band_generation: Some(hasher.finish()),
+                ..self
+            }
+        }
+
+        /// Sets whether the [`Table`] should minimize overdraw when painting row backgrounds,
+        /// by merging adjacent rows of the same appearance into a single quad.
+        ///
+        /// This is enabled by default on `wasm32`, where each quad is comparatively
+        /// expensive to paint with the canvas and WebGL backends.
+        pub fn minimize_overdraw(self, minimize_overdraw: bool) -> Self {
+            Self {
+                minimize_overdraw,
+                ..self
+            }
+        }
+
+        /// Sets whether the [`Table`] draws its layout over its content, to help diagnose
+        /// layout issues.
+        ///
+        /// Each cell is outlined, header cells are labeled with their column index and the
+        /// spacer enforcing the [`min_width`](Self::min_width) is highlighted. Since the
+        /// outlines of each band are drawn with its scroll offset applied, a header which
+        /// is out of sync with the body is easy to spot.
+        pub fn debug_overlay(self, debug_overlay: bool) -> Self {
+            Self {
+                debug_overlay,
+                ..self
+            }
+        }
+
+        /// Sets the style of this [`Table`].
+        pub fn style(self, style: impl Fn(&Theme, style::Status) -> container::Style + 'a) -> Self
+        where
+            <Theme as style::Catalog>::Class<'a>: From<style::StyleFn<'a, Theme>>,
+        {
+            Self {
+                class: Rc::new((Rc::new(style) as style::StyleFn<'a, Theme>).into()),
+                ..self
+            }
+        }
+
+        /// Sets the style class of this [`Table`].
+        pub fn class(self, class: impl Into<<Theme as style::Catalog>::Class<'a>>) -> Self {
+            Self {
+                class: Rc::new(class.into()),
+                ..self
+            }
+        }
+
+        ///  Sets the [`Scrollbar`](iced_widget::scrollable::Scrollbar) used for the table's body scrollable.
+        pub fn scrollbar(self, scrollbar: scrollable::Scrollbar) -> Self {
+            Self { scrollbar, ..self }
+        }
+
+        /// Sets whether the body is only scrolled along the axes its rows overflow, passing the
+        /// rest of the mouse wheel on to the parent, such as a scrollable the [`Table`] is
+        /// embedded in.
+        ///
+        /// The scrollbars are already hidden along the axes the rows fit in, unless the
+        /// [`scrollbar`](Self::scrollbar) has a spacing.
+        pub fn scroll_overflow_only(self, scroll_overflow_only: bool) -> Self {
+            Self {
+                scroll_overflow_only,
+                ..self
+            }
+        }
+
+        // How the bounds of the cells are drawn, with the labels of the columns if `labeled`
+        fn debug(&self, labeled: bool) -> DebugMode {
+            match (self.debug_overlay, labeled) {
+                (false, _) => DebugMode::Off,
+                (true, false) => DebugMode::Bounds,
+                (true, true) => DebugMode::Labeled,
+            }
+        }
+
+        #[cfg(feature = "testing")]
+        pub(crate) fn column_count<Renderer>(&self) -> usize
+        where
+            Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        {
+            self.columns
+                .iter()
+                .filter(|column| column.is_visible())
+                .count()
+        }
+
+        #[cfg(feature = "testing")]
+        pub(crate) fn has_header(&self) -> bool {
+            self.header
+        }
+
+        // Whether there are bands of rows pinned above & below the body
+        #[cfg(feature = "testing")]
+        pub(crate) fn has_pinned_rows(&self) -> (bool, bool) {
+            (
+                !self.pinned_rows_top.is_empty(),
+                !self.pinned_rows_bottom.is_empty(),
+            )
+        }
+
+        // Whether the body scrollable is wrapped to limit its height
+        #[cfg(feature = "testing")]
+        pub(crate) fn has_max_body_height(&self) -> bool {
+            self.max_body_height.is_some()
+        }
+
+        // Whether the header has a band for the groups of the displayed columns
+        #[cfg(feature = "testing")]
+        pub(crate) fn has_column_groups<Renderer>(&self) -> bool
+        where
+            Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        {
+            self.columns
+                .iter()
+                .any(|column| column.is_visible() && column.group().is_some())
+        }
+    }
+
+    impl<'a, Column, Row, Message, Theme, Renderer> From<Table<'a, Column, Row, Message, Theme>>
+        for Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog
+            + container::Catalog
+            + scrollable::Catalog
+            + text::Catalog
+            + button::Catalog
+            + 'a,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        fn from(table: Table<'a, Column, Row, Message, Theme>) -> Self {
+            let started = Instant::now();
+
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!(
+                "view",
+                rows = table.rows.len(),
+                columns = table.columns.len()
+            )
+            .entered();
+
+            let view = View::new(&table);
+
+            let header = header_band(&table, &view);
+            let body = body_bands(&table, &view);
+            let footer = footer_band(&table, &view);
+
+            assemble(table, view, header, body, footer, started)
+        }
+    }
+
+    // What the header, body & footer of a table are built from, computed once per view
+    struct View<'a, Theme>
+    where
+        Theme: style::Catalog,
+    {
+        // The indices of the displayed rows, once filtered, collapsed & grouped
+        displayed: Vec<usize>,
+        row_groups: Vec<RowGroup>,
+        // The indices of the visible columns
+        shown: Rc<[usize]>,
+        selected_columns: Vec<usize>,
+        selected_rows: Rc<HashSet<usize>>,
+        widths: Rc<[f32]>,
+        // The frozen columns which are displayed
+        frozen_columns: usize,
+        cell_spacing: f32,
+        resize_mode: ResizeMode,
+        resizing: Resizing,
+        // Where the table was right-clicked, as reported to on_header_right_click
+        right_press: Option<RightPress>,
+        // The rows & cells built, including the rows of a virtualized body built later
+        built: Rc<Built>,
+        // The key of the cached layout of the header & footer, if cached
+        band_key: Option<u64>,
+        class: style::Shared<'a, Theme>,
+    }
+
+    impl<'a, Theme> View<'a, Theme>
+    where
+        Theme: style::Catalog + container::Catalog,
+    {
+        fn new<Column, Row, Message, Renderer>(
+            table: &Table<'a, Column, Row, Message, Theme>,
+        ) -> Self
+        where
+            Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        {
+            let Table {
+                columns,
+                ref rows,
+                ref tree,
+                ref group_by,
+                ref filter,
+                filter_query,
+                selected_rows,
+                frozen_columns,
+                min_width,
+                min_column_width,
+                divider_width,
+                cell_spacing,
+                resize_mode,
+                cell_padding,
+                clip_cells,
+                align_y,
+                band_generation,
+                ref on_header_right_click,
+                ref class,
+                ..
+            } = *table;
+
+            let displayed: Vec<usize> = match tree {
+                Some((depth, is_expanded, _)) => {
+                    // The depth of the collapsed row whose descendants are hidden
+                    let mut collapsed: Option<usize> = None;
+
+                    (0..rows.len())
+                        .filter(|&index| {
+                            let row_depth = (depth)(rows.get(index));
+
+                            if collapsed.is_some_and(|collapsed| row_depth > collapsed) {
+                                return false;
+                            }
+
+                            collapsed = (!(is_expanded)(rows.get(index))).then_some(row_depth);
+
+                            true
+                        })
+                        .collect()
+                }
+                None => (0..rows.len()).collect(),
+            };
+
+            let displayed = match filter {
+                Some(filter) if !filter_query.is_empty() => displayed
+                    .into_iter()
+                    .filter(|&index| (filter)(rows.get(index), filter_query))
+                    .collect(),
+                _ => displayed,
+            };
+
+            let (displayed, row_groups) = match group_by {
+                Some((same_group, is_expanded, _)) => {
+                    group_rows(rows, displayed, &**same_group, &**is_expanded)
+                }
+                None => (displayed, Vec::new()),
+            };
+
+            let selected_columns: Vec<usize> = columns
+                .iter()
+                .enumerate()
+                .filter_map(|(index, column)| column.is_selected().then_some(index))
+                .collect();
+
+            let shown: Rc<[usize]> = columns
+                .iter()
+                .enumerate()
+                .filter_map(|(index, column)| column.is_visible().then_some(index))
+                .collect();
+
+            let frozen_columns = shown
+                .iter()
+                .filter(|&&index| index < frozen_columns)
+                .count();
+
+            let widths: Rc<[f32]> = column_widths(columns, min_width, min_column_width).into();
+            let cell_spacing = cell_spacing.unwrap_or(divider_width);
+
+            let band_key = band_generation.map(|generation| {
+                let mut hasher = DefaultHasher::new();
+
+                generation.hash(&mut hasher);
+                frozen_columns.hash(&mut hasher);
+                for &index in shown.iter() {
+                    let column = &columns[index];
+
+                    index.hash(&mut hasher);
+                    clamp_width(column, widths[index], min_column_width)
+                        .to_bits()
+                        .hash(&mut hasher);
+                    column.align_y().unwrap_or(align_y).hash(&mut hasher);
+                    column.sort_order().hash(&mut hasher);
+                }
+                for value in [
+                    min_width,
+                    cell_spacing,
+                    cell_padding.top,
+                    cell_padding.right,
+                    cell_padding.bottom,
+                    cell_padding.left,
+                ] {
+                    value.to_bits().hash(&mut hasher);
+                }
+                clip_cells.hash(&mut hasher);
+
+                hasher.finish()
+            });
+
+            Self {
+                displayed,
+                row_groups,
+                shown,
+                selected_columns,
+                // Looked up for each row built & copied
+                selected_rows: Rc::new(selected_rows.iter().copied().collect()),
+                widths,
+                frozen_columns,
+                cell_spacing,
+                resize_mode,
+                resizing: Resizing::default(),
+                right_press: on_header_right_click
+                    .as_ref()
+                    .map(|_| RightPress::default()),
+                built: Rc::new(Built::default()),
+                band_key,
+                class: class.clone(),
+            }
+        }
+
+        // The column resized along with the one at `position`, if any
+        fn neighbor<'b, Column>(
+            &self,
+            columns: &'b [Column],
+            position: usize,
+        ) -> Option<(usize, &'b Column, f32)> {
+            self.shown
+                .get(position + 1)
+                .filter(|_| self.resize_mode == ResizeMode::Neighbor)
+                .map(|&next| (next, &columns[next], self.widths[next]))
+        }
+    }
+
+    // The body of a table, along with the bands of its pinned rows
+    struct Body<'a, Message, Theme, Renderer> {
+        body: Element<'a, Message, Theme, Renderer>,
+        pinned_top: Option<Element<'a, Message, Theme, Renderer>>,
+        pinned_bottom: Option<Element<'a, Message, Theme, Renderer>>,
+        // The part of the body in view, once virtualized
+        visible: Option<Visible>,
+    }
+
+    // A band scrolled horizontally along with the body, without scrollbars of its own
+    fn band<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: scrollable::Catalog + 'a,
+        Message: 'a,
+    {
+        scrollable(content)
             .direction(scrollable::Direction::Both {
                 vertical: scrollable::Scrollbar::new()
                     .width(0)
@@ -235,157 +1994,1485 @@ pub mod table {
                     .width(0)
                     .margin(0)
                     .scroller_width(0),
+            })
+            .into()
+    }
+
+    // The header of the columns, below the band of their groups if any
+    fn header_band<'a, Column, Row, Message, Theme, Renderer>(
+        table: &Table<'a, Column, Row, Message, Theme>,
+        view: &View<'a, Theme>,
+    ) -> Option<Element<'a, Message, Theme, Renderer>>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog
+            + container::Catalog
+            + scrollable::Catalog
+            + text::Catalog
+            + button::Catalog
+            + 'a,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let Table {
+            header,
+            columns,
+            ref on_columns_selected,
+            ref on_sort,
+            ref on_sort_append,
+            ref on_header_right_click,
+            ref on_column_resize,
+            ref on_column_autofit,
+            ref on_column_reset,
+            ref on_column_reorder,
+            min_width,
+            min_column_width,
+            divider_width,
+            divider_hit_area,
+            resize_step,
+            cell_padding,
+            clip_cells,
+            align_y: table_align_y,
+            tooltip_position,
+            tooltip_delay,
+            ..
+        } = *table;
+
+        if !header {
+            return None;
+        }
+
+        let View {
+            ref shown,
+            ref widths,
+            ref selected_columns,
+            ref resizing,
+            ref right_press,
+            ref class,
+            frozen_columns,
+            cell_spacing,
+            ..
+        } = *view;
+
+        let align_y = |column: &Column| column.align_y().unwrap_or(table_align_y);
+
+        let header_cells = Cells::new(shown.iter().enumerate().map(|(position, &index)| {
+            let column = &columns[index];
+
+            let on_select = on_columns_selected.clone().map(|on_columns_selected| {
+                let selected_columns = selected_columns.clone();
+
+                move |modifiers: keyboard::Modifiers| {
+                    let mut selection = selected_columns.clone();
+
+                    if !modifiers.command() {
+                        selection = vec![index];
+                    } else if let Some(position) =
+                        selection.iter().position(|selected| *selected == index)
+                    {
+                        selection.remove(position);
+                    } else {
+                        selection.push(index);
+                        selection.sort_unstable();
+                    }
+
+                    (on_columns_selected)(selection)
+                }
             });
 
-            let body = scrollable(column(rows.iter().enumerate().map(|(row_index, _row)| {
-                style::wrapper::row(
-                    row(columns
-                        .iter()
-                        .enumerate()
-                        .map(|(col_index, column)| {
-                            body_container(
-                                col_index,
-                                row_index,
-                                column,
-                                _row,
-                                min_column_width,
-                                divider_width,
-                                cell_padding,
-                            )
-                        })
-                        .chain(dummy_container(columns, min_width, min_column_width))),
-                    style.clone(),
-                    row_index,
+            let cell = header_container(
+                index,
+                position,
+                column,
+                widths[index],
+                on_select,
+                on_sort.clone(),
+                on_sort_append.clone(),
+                on_header_right_click.clone().zip(right_press.clone()),
+                on_column_resize.as_ref(),
+                on_column_autofit.as_deref(),
+                on_column_reset.as_deref(),
+                resizing.clone(),
+                min_column_width,
+                divider_width,
+                divider_hit_area,
+                cell_spacing,
+                resize_step,
+                view.neighbor(columns, position),
+                cell_padding,
+                clip_cells,
+                tooltip_position,
+                tooltip_delay,
+                class.clone(),
+            );
+
+            (cell, align_y(column))
+        }))
+        .push_maybe(dummy_container(
+            columns,
+            widths,
+            min_width,
+            min_column_width,
+        ))
+        .frozen(frozen_columns)
+        .resizing(resizing.clone())
+        .debug(table.debug(true));
+
+        let header_cells = match on_column_reorder {
+            Some(on_column_reorder) => Reorder::new(
+                header_cells,
+                Axis::Horizontal,
+                shown.iter().copied().map(Some).collect(),
+                on_column_reorder.clone(),
+                class.clone(),
+            )
+            .into(),
+            None => Element::from(header_cells),
+        };
+
+        let header_cells = if shown.iter().any(|&index| columns[index].group().is_some()) {
+            column![
+                group_band(
+                    columns,
+                    shown,
+                    widths,
+                    min_width,
+                    min_column_width,
+                    cell_spacing,
+                    cell_padding,
+                    clip_cells,
+                    table_align_y,
+                    frozen_columns,
+                    resizing.clone(),
+                    table.debug(false),
+                ),
+                header_cells,
+            ]
+            .into()
+        } else {
+            header_cells
+        };
+
+        Some(band(Cached::new(
+            style::wrapper::header(header_cells, class.clone()),
+            view.band_key,
+            resizing.clone(),
+        )))
+    }
+
+    // The body of the rows, along with the bands of the rows pinned above & below it
+    fn body_bands<'a, Column, Row, Message, Theme, Renderer>(
+        table: &Table<'a, Column, Row, Message, Theme>,
+        view: &View<'a, Theme>,
+    ) -> Body<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog
+            + container::Catalog
+            + scrollable::Catalog
+            + text::Catalog
+            + button::Catalog
+            + 'a,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let Table {
+            columns,
+            rows: ref all_rows,
+            ref on_scroll,
+            ref on_row_reorder,
+            ref on_row_click,
+            ref on_row_double_click,
+            ref on_cell_click,
+            ref on_row_hover,
+            ref on_rows_visible,
+            ref row_key,
+            anchor_rows,
+            focused_cell,
+            ref on_cell_focus,
+            editing_cell,
+            ref on_cell_edit,
+            pinned_rows_top,
+            pinned_rows_bottom,
+            ref row_style,
+            ref row_kind,
+            failed_rows,
+            ref on_retry,
+            ref same_group,
+            grand_total,
+            loading_rows,
+            ref is_loaded,
+            loading_more,
+            ref loading_more_label,
+            ref row_height,
+            virtualized,
+            ref tree,
+            ref group_by,
+            overscan,
+            min_width,
+            max_body_height,
+            min_column_width,
+            divider_width,
+            cell_padding,
+            clip_cells,
+            gridlines,
+            align_y: table_align_y,
+            tooltip_position,
+            tooltip_delay,
+            scrollbar,
+            minimize_overdraw,
+            ..
+        } = *table;
+
+        let View {
+            ref displayed,
+            ref row_groups,
+            ref shown,
+            ref widths,
+            ref selected_rows,
+            ref resizing,
+            ref built,
+            ref class,
+            frozen_columns,
+            cell_spacing,
+            ..
+        } = *view;
+
+        let rows = all_rows;
+        let align_y = move |column: &Column| column.align_y().unwrap_or(table_align_y);
+        let debug = |labeled| table.debug(labeled);
+
+        let heights: Option<Vec<f32>> = row_height.as_ref().map(|row_height| {
+            displayed
+                .iter()
+                .map(|&row_index| (row_height)(row_index, rows.get(row_index)))
+                .collect()
+        });
+
+        // The appearance of a row of cells
+        let styled_row = |row_index: usize| StyledRow {
+            index: row_index,
+            total: rows.len(),
+            style: row_style
+                .as_ref()
+                .map(|row_style| (row_style)(row_index, rows.get(row_index)))
+                .unwrap_or_default(),
+            selected: selected_rows.contains(&row_index),
+        };
+
+        // A row of cells, given its appearance & height, which may be built lazily
+        let cells_row = Rc::new({
+            let built = built.clone();
+            let rows = all_rows.clone();
+            let shown = shown.clone();
+            let widths = widths.clone();
+            let class = class.clone();
+            let resizing = resizing.clone();
+            let tree = tree.clone();
+            let on_cell_focus = on_cell_focus.clone();
+            let on_cell_edit = on_cell_edit.clone();
+            let on_cell_click = on_cell_click.clone();
+            let on_row_click = on_row_click.clone();
+            let on_row_double_click = on_row_double_click.clone();
+            let debug = debug(false);
+
+            move |row_index: usize, styled_row: StyledRow, height: Option<f32>| {
+                let _row = rows.get(row_index);
+
+                // The positions of the displayed columns starting a cell, along with
+                // the number of columns spanned by the cell
+                let mut spans = Vec::with_capacity(shown.len());
+                let mut start = 0;
+
+                while let Some(&col_index) = shown.get(start) {
+                    let span = columns[col_index]
+                        .cell_span(col_index, row_index, _row)
+                        .clamp(1, shown.len() - start);
+
+                    spans.push((start, span));
+                    start += span;
+                }
+
+                built.row(spans.len());
+
+                let frozen_cells = spans
+                    .iter()
+                    .filter(|(start, _)| *start < frozen_columns)
+                    .count();
+                let cell_spans = spans
+                    .iter()
+                    .map(|&(start, span)| start..start + span)
+                    .collect();
+                let cell_keys = spans.iter().map(|&(start, _)| shown[start]).collect();
+
+                let row = style::wrapper::row(
+                    Cells::new(spans.into_iter().map(|(start, span)| {
+                        let col_index = shown[start];
+                        let column = &columns[col_index];
+                        let width = shown[start..start + span]
+                            .iter()
+                            .map(|&index| {
+                                clamp_width(&columns[index], widths[index], min_column_width)
+                            })
+                            .sum();
+                        let branch = tree
+                            .as_ref()
+                            .filter(|_| shown.first() == Some(&col_index))
+                            .map(|(depth, is_expanded, on_toggle)| {
+                                let row_depth = (depth)(_row);
+                                let has_children = row_index + 1 < rows.len()
+                                    && (depth)(rows.get(row_index + 1)) > row_depth;
+
+                                tree_branch(
+                                    row_index,
+                                    row_depth,
+                                    has_children.then(|| (is_expanded)(_row)),
+                                    on_toggle.clone(),
+                                )
+                            });
+
+                        let cell = body_container(
+                            col_index,
+                            row_index,
+                            column,
+                            width,
+                            _row,
+                            branch,
+                            focused_cell == Some((row_index, col_index)),
+                            on_cell_focus.clone(),
+                            editing_cell == Some((row_index, col_index)),
+                            on_cell_edit.clone(),
+                            on_cell_click.clone(),
+                            on_row_click.clone(),
+                            on_row_double_click.clone(),
+                            divider_width,
+                            cell_spacing,
+                            cell_padding,
+                            clip_cells,
+                            gridlines,
+                            tooltip_position,
+                            tooltip_delay,
+                            class.clone(),
+                        );
+
+                        (cell, align_y(column))
+                    }))
+                    .push_maybe(dummy_container(
+                        columns,
+                        &widths,
+                        min_width,
+                        min_column_width,
+                    ))
+                    .height(height)
+                    .frozen(frozen_cells)
+                    .resizing(resizing.clone())
+                    .spans(cell_spans)
+                    .keys(row_index, cell_keys)
+                    .debug(debug),
+                    class.clone(),
+                    styled_row,
+                    minimize_overdraw,
+                );
+
+                if on_row_click.is_some() || on_row_double_click.is_some() {
+                    let mut clickable = Clickable::new(row);
+
+                    if let Some(on_row_click) = on_row_click.clone() {
+                        clickable = clickable.on_press(move |_| (on_row_click)(row_index));
+                    }
+
+                    if let Some(on_row_double_click) = on_row_double_click.clone() {
+                        clickable =
+                            clickable.on_double_press(move || (on_row_double_click)(row_index));
+                    }
+
+                    clickable.into()
+                } else {
+                    row
+                }
+            }
+        });
+
+        // A placeholder row, until the row is fetched
+        let skeleton = Rc::new({
+            let shown = shown.clone();
+            let widths = widths.clone();
+            let class = class.clone();
+            let resizing = resizing.clone();
+
+            move || {
+                skeleton_row(
+                    columns,
+                    &shown,
+                    &widths,
+                    min_width,
+                    min_column_width,
+                    cell_spacing,
+                    cell_padding,
+                    frozen_columns,
+                    resizing.clone(),
+                    class.clone(),
                 )
-                .into()
-            })))
-            .id(body)
-            .on_scroll(move |viewport| {
-                let offset = viewport.absolute_offset();
+            }
+        });
 
-                (on_sync)(scrollable::AbsoluteOffset { y: 0.0, ..offset })
-            })
+        // The header band of a group of rows
+        let row_group_band = |group: &RowGroup| {
+            row_group_band(
+                columns,
+                widths,
+                Displayed {
+                    rows,
+                    indices: &group.rows,
+                },
+                group.expanded,
+                group_by
+                    .as_ref()
+                    .map(|(_, _, on_toggle)| on_toggle.clone())
+                    .expect("rows are grouped"),
+                min_width,
+                min_column_width,
+                cell_spacing,
+                cell_padding,
+                clip_cells,
+                table_align_y,
+                frozen_columns,
+                resizing.clone(),
+                debug(false),
+                class.clone(),
+            )
+        };
+
+        let mut row_groups = row_groups.iter().peekable();
+
+        let mut body_rows = Vec::with_capacity(displayed.len());
+        let mut body_indices = Vec::with_capacity(displayed.len());
+        let mut failed_shown = vec![false; failed_rows.len()];
+
+        let mut group_start = 0;
+
+        for (position, &row_index) in displayed.iter().enumerate() {
+            let height = heights.as_ref().map(|heights| heights[position]);
+
+            // A row built right away, or once scrolled into view when virtualized
+            let lazy_row =
+                |build: Build<'a, Message, Theme, Renderer>| match height.filter(|_| virtualized) {
+                    Some(height) => Entry::Lazy {
+                        row: row_index,
+                        height,
+                        build,
+                    },
+                    None => Entry::Built(build()),
+                };
+
+            while let Some(group) = row_groups.next_if(|group| group.start == position) {
+                body_rows.push(Entry::Built(row_group_band(group)));
+                body_indices.push(None);
+            }
+
+            if let Some(failed) = failed_rows
+                .iter()
+                .position(|range| range.contains(&row_index))
+            {
+                // A single band for all the displayed rows of the range
+                if !std::mem::replace(&mut failed_shown[failed], true) {
+                    body_rows.push(Entry::Built(error_container(
+                        failed_rows[failed].clone(),
+                        on_retry.clone(),
+                        total_width(columns, widths, min_width, min_column_width),
+                        cell_padding,
+                        class.clone(),
+                    )));
+                    body_indices.push(None);
+                }
+            } else if is_loaded
+                .as_ref()
+                .is_some_and(|is_loaded| !is_loaded(row_index))
+            {
+                let skeleton = skeleton.clone();
+                let class = class.clone();
+                let styled_row = StyledRow::new(row_index, rows.len());
+
+                body_rows.push(lazy_row(Box::new(move || {
+                    style::wrapper::row((skeleton)(), class.clone(), styled_row, false)
+                })));
+                body_indices.push(None);
+            } else if let Some(&col_index) = shown.first().filter(|_| {
+                row_kind.as_ref().is_some_and(|row_kind| {
+                    (row_kind)(row_index, rows.get(row_index)) == RowKind::FullWidth
+                })
+            }) {
+                let row = rows.get(row_index);
+                let width = total_width(columns, widths, min_width, min_column_width);
+                let class = class.clone();
+                let built = built.clone();
+
+                body_rows.push(lazy_row(Box::new(move || {
+                    built.row(1);
+
+                    full_width_container(
+                        col_index,
+                        row_index,
+                        &columns[col_index],
+                        row,
+                        width,
+                        height,
+                        cell_padding,
+                        class.clone(),
+                    )
+                })));
+                body_indices.push(None);
+            } else {
+                let cells_row = cells_row.clone();
+                let styled_row = styled_row(row_index);
+
+                body_rows.push(lazy_row(Box::new(move || {
+                    (cells_row)(row_index, styled_row, height)
+                })));
+                body_indices.push(Some(styled_row));
+            }
+
+            if let Some(same_group) = same_group {
+                let is_group_end = match displayed.get(position + 1) {
+                    Some(&next) => !(same_group)(rows.get(row_index), rows.get(next)),
+                    None => true,
+                };
+
+                if is_group_end {
+                    body_rows.push(Entry::Built(summary_band(
+                        columns,
+                        widths,
+                        SummaryLevel::Subtotal,
+                        Displayed {
+                            rows,
+                            indices: &displayed[group_start..=position],
+                        },
+                        min_width,
+                        min_column_width,
+                        cell_spacing,
+                        cell_padding,
+                        clip_cells,
+                        table_align_y,
+                        frozen_columns,
+                        resizing.clone(),
+                        debug(false),
+                        class.clone(),
+                    )));
+                    body_indices.push(None);
+
+                    group_start = position + 1;
+                }
+            }
+        }
+
+        // The collapsed groups after the last displayed row
+        for group in row_groups {
+            body_rows.push(Entry::Built(row_group_band(group)));
+            body_indices.push(None);
+        }
+
+        if grand_total {
+            body_rows.push(Entry::Built(summary_band(
+                columns,
+                widths,
+                SummaryLevel::GrandTotal,
+                Displayed {
+                    rows,
+                    indices: displayed,
+                },
+                min_width,
+                min_column_width,
+                cell_spacing,
+                cell_padding,
+                clip_cells,
+                table_align_y,
+                frozen_columns,
+                resizing.clone(),
+                debug(false),
+                class.clone(),
+            )));
+            body_indices.push(None);
+        }
+
+        // The rows which can be dragged, leaving out the loading rows
+        let draggable_rows = on_row_reorder.as_ref().map(|_| {
+            body_indices
+                .iter()
+                .map(|row| row.map(|row| row.index))
+                .collect()
+        });
+
+        // The keys of the rows the body can be anchored to, leaving out the loading rows
+        let anchored_rows = row_key.as_ref().filter(|_| anchor_rows).map(|row_key| {
+            body_indices
+                .iter()
+                .map(|row| row.map(|row| (row_key)(rows.get(row.index))))
+                .collect()
+        });
+
+        // The rows which can be hovered, leaving out the loading rows
+        let hovered_rows = on_row_hover.as_ref().map(|_| {
+            body_indices
+                .iter()
+                .map(|row| row.map(|row| row.index))
+                .collect()
+        });
+
+        for index in rows.len()..rows.len() + loading_rows {
+            // Striped as the rows to be loaded
+            body_rows.push(Entry::Built(style::wrapper::row(
+                (skeleton)(),
+                class.clone(),
+                StyledRow::new(index, rows.len()),
+                minimize_overdraw,
+            )));
+            body_indices.push(Some(StyledRow::new(index, rows.len())));
+        }
+
+        if loading_more {
+            // Striped as the next row to be loaded
+            body_rows.push(Entry::Built(style::wrapper::row(
+                container(text(loading_more_label.clone()))
+                    .width(total_width(columns, widths, min_width, min_column_width))
+                    .padding(cell_padding)
+                    .align_x(alignment::Horizontal::Center),
+                class.clone(),
+                StyledRow::new(rows.len() + loading_rows, rows.len()),
+                minimize_overdraw,
+            )));
+            body_indices.push(Some(StyledRow::new(rows.len() + loading_rows, rows.len())));
+        }
+        // The keys of the rows, keeping their widget state as they move
+        let body_keys = row_key.as_ref().map(|row_key| {
+            body_indices
+                .iter()
+                .enumerate()
+                .map(|(position, row)| match row {
+                    Some(row) if row.index < rows.len() => {
+                        BodyKey::Row((row_key)(rows.get(row.index)))
+                    }
+                    _ => BodyKey::Position(position),
+                })
+                .collect()
+        });
+
+        let visible = virtualized.then(Visible::default);
+
+        let body_rows: Element<'a, _, _, _> = match &visible {
+            Some(visible) => Lazy::new(body_rows, visible.clone(), overscan)
+                .on_rows_visible(on_rows_visible.clone())
+                .into(),
+            None => column(body_rows.into_iter().map(Entry::build)).into(),
+        };
+
+        let body_rows: Element<'a, _, _, _> = match body_keys {
+            Some(body_keys) => Keyed::new(body_rows, body_keys).into(),
+            None => body_rows,
+        };
+
+        let body_rows = if minimize_overdraw {
+            style::wrapper::body(body_rows, class.clone(), body_indices)
+        } else {
+            body_rows
+        };
+
+        let body_rows = match on_row_hover.clone().zip(hovered_rows) {
+            Some((on_row_hover, hovered_rows)) => {
+                Hover::new(body_rows, hovered_rows, on_row_hover).into()
+            }
+            None => body_rows,
+        };
+
+        let body_rows = match anchored_rows {
+            Some(anchored_rows) => Anchor::new(body_rows, anchored_rows).into(),
+            None => body_rows,
+        };
+
+        let body_rows = match on_row_reorder.clone().zip(draggable_rows) {
+            Some((on_row_reorder, draggable_rows)) => Reorder::new(
+                body_rows,
+                Axis::Vertical,
+                draggable_rows,
+                on_row_reorder,
+                class.clone(),
+            )
+            .into(),
+            None => body_rows,
+        };
+
+        let body = scrollable(body_rows)
             .direction(scrollable::Direction::Both {
                 horizontal: scrollbar,
                 vertical: scrollbar,
             })
-            .height(Length::Fill);
-
-            let footer = footer.map(|footer| {
-                scrollable(style::wrapper::footer(
-                    row(columns
-                        .iter()
-                        .enumerate()
-                        .map(|(index, column)| {
-                            footer_container(
-                                index,
-                                column,
-                                rows,
-                                on_column_drag,
-                                on_column_release.clone(),
-                                min_column_width,
-                                divider_width,
-                                cell_padding,
-                                style.clone(),
-                            )
-                        })
-                        .chain(dummy_container(columns, min_width, min_column_width))),
-                    style,
-                ))
-                .id(footer)
-                .direction(scrollable::Direction::Both {
-                    vertical: scrollable::Scrollbar::new()
-                        .width(0)
-                        .margin(0)
-                        .scroller_width(0),
-                    horizontal: scrollable::Scrollbar::new()
-                        .width(0)
-                        .margin(0)
-                        .scroller_width(0),
-                })
+            .height(if max_body_height.is_some() {
+                Length::Shrink
+            } else {
+                Length::Fill
             });
 
-            let mut column = column![header, body];
+        let body = match on_scroll.clone() {
+            Some(on_scroll) => body.on_scroll(move |viewport| (on_scroll)(viewport)),
+            None => body,
+        };
+
+        let body: Element<'a, _, _, _> = match max_body_height {
+            Some(max_height) => container(body).max_height(max_height).into(),
+            None => body.into(),
+        };
+
+        // A band of pinned rows, scrolled horizontally along with the body
+        let pinned_band = |pinned_rows: &[usize]| {
+            let (pinned_rows, pinned_indices): (Vec<_>, Vec<_>) = pinned_rows
+                .iter()
+                .filter(|&&row_index| row_index < rows.len())
+                .map(|&row_index| {
+                    let styled_row = styled_row(row_index);
+                    let row = (cells_row)(
+                        row_index,
+                        styled_row,
+                        row_height
+                            .as_ref()
+                            .map(|row_height| (row_height)(row_index, rows.get(row_index))),
+                    );
+
+                    (row, Some(styled_row))
+                })
+                .unzip();
+
+            let pinned_rows = column(pinned_rows);
+
+            let pinned_rows = if minimize_overdraw {
+                style::wrapper::body(pinned_rows, class.clone(), pinned_indices)
+            } else {
+                pinned_rows.into()
+            };
+
+            band(pinned_rows)
+        };
+
+        Body {
+            body,
+            pinned_top: (!pinned_rows_top.is_empty()).then(|| pinned_band(pinned_rows_top)),
+            pinned_bottom: (!pinned_rows_bottom.is_empty())
+                .then(|| pinned_band(pinned_rows_bottom)),
+            visible,
+        }
+    }
+
+    // The footer of the columns, with the summary of the displayed rows
+    fn footer_band<'a, Column, Row, Message, Theme, Renderer>(
+        table: &Table<'a, Column, Row, Message, Theme>,
+        view: &View<'a, Theme>,
+    ) -> Option<Element<'a, Message, Theme, Renderer>>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog
+            + container::Catalog
+            + scrollable::Catalog
+            + text::Catalog
+            + button::Catalog
+            + 'a,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let Table {
+            footer,
+            columns,
+            ref rows,
+            ref on_column_resize,
+            ref on_column_autofit,
+            ref on_column_reset,
+            min_width,
+            min_column_width,
+            divider_width,
+            divider_hit_area,
+            resize_step,
+            cell_padding,
+            clip_cells,
+            align_y: table_align_y,
+            ..
+        } = *table;
+
+        if !footer {
+            return None;
+        }
+
+        let View {
+            ref displayed,
+            ref shown,
+            ref widths,
+            ref resizing,
+            ref class,
+            frozen_columns,
+            cell_spacing,
+            ..
+        } = *view;
+
+        let align_y = |column: &Column| column.align_y().unwrap_or(table_align_y);
+
+        let footer_cells = Cells::new(shown.iter().enumerate().map(|(position, &index)| {
+            let column = &columns[index];
+            let cell = footer_container(
+                index,
+                position,
+                column,
+                widths[index],
+                Displayed {
+                    rows,
+                    indices: displayed,
+                },
+                on_column_resize.as_ref(),
+                on_column_autofit.as_deref(),
+                on_column_reset.as_deref(),
+                resizing.clone(),
+                min_column_width,
+                divider_width,
+                divider_hit_area,
+                cell_spacing,
+                resize_step,
+                view.neighbor(columns, position),
+                cell_padding,
+                clip_cells,
+                class.clone(),
+            );
+
+            (cell, align_y(column))
+        }))
+        .push_maybe(dummy_container(
+            columns,
+            widths,
+            min_width,
+            min_column_width,
+        ))
+        .frozen(frozen_columns)
+        .resizing(resizing.clone())
+        .debug(table.debug(false));
+
+        Some(band(Cached::new(
+            style::wrapper::footer(footer_cells, class.clone()),
+            view.band_key,
+            resizing.clone(),
+        )))
+    }
+
+    // Stacks the bands of a table, wrapped in the widgets handling the table as a whole
+    fn assemble<'a, Column, Row, Message, Theme, Renderer>(
+        table: Table<'a, Column, Row, Message, Theme>,
+        view: View<'a, Theme>,
+        header: Option<Element<'a, Message, Theme, Renderer>>,
+        body: Body<'a, Message, Theme, Renderer>,
+        footer: Option<Element<'a, Message, Theme, Renderer>>,
+        started: Instant,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog
+            + container::Catalog
+            + scrollable::Catalog
+            + text::Catalog
+            + button::Catalog
+            + 'a,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let Table {
+            id,
+            columns,
+            rows: all_rows,
+            on_column_copied,
+            on_render_stats,
+            on_scroll_near_end,
+            follow,
+            scroll_overflow_only,
+            row_key,
+            anchor_rows,
+            focused_cell,
+            on_cell_focus,
+            editing_cell,
+            on_cell_edit,
+            width,
+            height,
+            min_column_width,
+            divider_width,
+            resize_animation,
+            cell_padding,
+            ..
+        } = table;
+
+        let View {
+            displayed,
+            shown,
+            selected_rows,
+            widths,
+            cell_spacing,
+            resizing,
+            right_press,
+            built,
+            ..
+        } = view;
+
+        let Body {
+            body,
+            pinned_top,
+            pinned_bottom,
+            visible,
+        } = body;
+
+        // The width of each column, before the on-going resize
+        let clamped_widths: Vec<f32> = columns
+            .iter()
+            .zip(widths.iter())
+            .map(|(column, width)| clamp_width(column, *width, min_column_width))
+            .collect();
+
+        // The rendered width of each column, along with the on-going resize
+        let rendered_widths = {
+            let resizing = resizing.clone();
+            let shown = shown.clone();
+            let widths = clamped_widths.clone();
+
+            move || {
+                widths
+                    .iter()
+                    .enumerate()
+                    .map(|(index, width)| {
+                        let offset = shown
+                            .iter()
+                            .position(|&shown| shown == index)
+                            .map_or(0.0, |position| resizing.offset(position..position + 1));
+
+                        width + offset
+                    })
+                    .collect()
+            }
+        };
+
+        let table = Bands::new(
+            [header, pinned_top].into_iter().flatten(),
+            body,
+            [pinned_bottom, footer].into_iter().flatten(),
+            resizing.clone(),
+        )
+        .visible(visible)
+        .right_press(right_press)
+        .on_scroll_near_end(on_scroll_near_end)
+        .follow(follow)
+        .scroll_overflow_only(scroll_overflow_only)
+        .anchor(anchor_rows && row_key.is_some())
+        .width(width)
+        .height(height);
+
+        #[cfg(feature = "tracing")]
+        let table = super::instrument::Instrumented::new(table, displayed.len(), shown.len());
+
+        let table: Element<'a, Message, Theme, Renderer> = match resize_animation {
+            Some(duration) => {
+                Animated::new(table, clamped_widths, shown.to_vec(), resizing, duration).into()
+            }
+            None => table.into(),
+        };
+
+        let table = Tabbing::new(table);
+
+        let table: Element<'a, Message, Theme, Renderer> = match on_cell_focus {
+            Some(on_cell_focus) => Navigator::new(
+                table,
+                displayed.clone(),
+                shown.to_vec(),
+                focused_cell,
+                on_cell_focus,
+            )
+            .into(),
+            None => table.into(),
+        };
+
+        let table = match editing_cell.zip(on_cell_edit) {
+            Some((editing_cell, on_cell_edit)) => Editing::new(
+                table,
+                displayed.clone(),
+                shown
+                    .iter()
+                    .copied()
+                    .filter(|&index| columns[index].is_editable())
+                    .collect(),
+                editing_cell,
+                on_cell_edit,
+            )
+            .into(),
+            None => table,
+        };
+
+        let table = if selected_rows.is_empty() {
+            table
+        } else {
+            let displayed = displayed.clone();
+            let shown = shown.clone();
+            let rows = all_rows.clone();
+
+            Copier::new(table, move || {
+                displayed
+                    .iter()
+                    .filter(|row_index| selected_rows.contains(row_index))
+                    .map(|&row_index| {
+                        shown
+                            .iter()
+                            .map(|&col_index| {
+                                columns[col_index]
+                                    .cell_text(col_index, row_index, rows.get(row_index))
+                                    .unwrap_or_default()
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\t")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .into()
+        };
+
+        let table: Element<'a, Message, Theme, Renderer> = match id {
+            Some(id) => Root::new(
+                table,
+                id,
+                {
+                    let displayed = displayed.clone();
+                    let rows = all_rows.clone();
+
+                    move |col_index| {
+                        let column = columns.get(col_index)?;
+
+                        Some(
+                            displayed
+                                .iter()
+                                .map(|&row_index| {
+                                    column
+                                        .cell_text(col_index, row_index, rows.get(row_index))
+                                        .unwrap_or_default()
+                                })
+                                .collect::<Vec<_>>()
+                                .join("\n"),
+                        )
+                    }
+                },
+                {
+                    let rows = all_rows.clone();
+
+                    move |renderer| {
+                        columns
+                            .iter()
+                            .enumerate()
+                            .map(|(col_index, column)| {
+                                let cells = displayed.iter().map(|&row_index| {
+                                    column.cell(col_index, row_index, rows.get(row_index))
+                                });
+                                let content = std::iter::once(column.header(col_index))
+                                    .chain(cells)
+                                    .map(|content| measure_width(&content, renderer))
+                                    .fold(0.0, f32::max);
+
+                                clamp_width(
+                                    column,
+                                    content
+                                        + cell_padding.horizontal()
+                                        + divider_width.max(cell_spacing),
+                                    min_column_width,
+                                )
+                            })
+                            .collect()
+                    }
+                },
+                rendered_widths,
+            )
+            .on_column_copied(on_column_copied)
+            .into(),
+            None => table,
+        };
+
+        match on_render_stats {
+            Some(on_render_stats) => {
+                let elapsed = started.elapsed();
 
-            if let Some(footer) = footer {
-                column = column.push(footer);
+                Reporter::new(table, move || {
+                    (on_render_stats)(Stats {
+                        rows: built.rows(),
+                        cells: built.cells(),
+                        elapsed,
+                    })
+                })
+                .into()
             }
+            None => table,
+        }
+    }
+
+    // The width of the content when unconstrained, ignoring content filling its parent
+    fn measure_width<Message, Theme, Renderer>(
+        content: &Element<'_, Message, Theme, Renderer>,
+        renderer: &Renderer,
+    ) -> f32
+    where
+        Renderer: iced_core::Renderer,
+    {
+        let mut tree = widget::Tree::new(content);
+        let limits = iced_core::layout::Limits::new(Size::ZERO, Size::INFINITY);
+        let width = content
+            .as_widget()
+            .layout(&mut tree, renderer, &limits)
+            .size()
+            .width;
 
-            column.height(Length::Fill).into()
+        if width.is_finite() {
+            width
+        } else {
+            0.0
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn header_container<'a, Column, Row, Message, Theme, Renderer>(
         index: usize,
+        position: usize,
         column: &'a Column,
-        on_drag: Option<fn(usize, f32) -> Message>,
-        on_release: Option<Message>,
+        width: f32,
+        on_select: Option<impl Fn(keyboard::Modifiers) -> Message + 'a>,
+        on_sort: Option<Rc<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+        on_sort_append: Option<Rc<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+        on_right_click: Option<OnRightClick<'a, Message>>,
+        on_resize: Option<&OnResize<'a, Message>>,
+        on_autofit: Option<&dyn Fn(usize) -> Message>,
+        on_reset: Option<&dyn Fn(usize) -> Message>,
+        resizing: Resizing,
         min_column_width: f32,
         divider_width: f32,
+        divider_hit_area: f32,
+        cell_spacing: f32,
+        resize_step: Option<f32>,
+        neighbor: Option<(usize, &'a Column, f32)>,
         cell_padding: Padding,
-        style: <Theme as style::Catalog>::Style,
+        clip_cells: bool,
+        tooltip_position: tooltip::Position,
+        tooltip_delay: Duration,
+        class: style::Shared<'a, Theme>,
     ) -> Element<'a, Message, Theme, Renderer>
     where
-        Renderer: iced_core::Renderer + 'a,
-        Theme: style::Catalog + container::Catalog + 'a,
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog + container::Catalog + text::Catalog + 'a,
         Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let content = container(column.header(index))
+        let sort_order = column.sort_order();
+
+        let header: Element<'a, Message, Theme, Renderer> = match sort_order {
+            Some(order) => row![
+                container(column.header(index)).width(Length::Fill),
+                style::wrapper::sort_indicator(
+                    match column.sort_rank() {
+                        Some(rank) => text!("{}{rank}", order.indicator()),
+                        None => text(order.indicator()),
+                    },
+                    class.clone()
+                ),
+            ]
+            .spacing(4)
+            .into(),
+            None => column.header(index),
+        };
+
+        let content = container(header)
             .width(Length::Fill)
             .padding(cell_padding)
-            .into();
+            .clip(clip_cells);
+
+        let content = if column.is_selected() {
+            style::wrapper::selected(content, class.clone())
+        } else {
+            content.into()
+        };
+
+        let content = if on_select.is_some() || on_sort.is_some() || on_right_click.is_some() {
+            let mut clickable = Clickable::new(content);
+
+            let on_sort = on_sort.map(|on_sort| {
+                let order = match sort_order {
+                    Some(SortOrder::Ascending) => SortOrder::Descending,
+                    _ => SortOrder::Ascending,
+                };
+
+                move |modifiers: keyboard::Modifiers| match on_sort_append
+                    .as_ref()
+                    .filter(|_| modifiers.shift())
+                {
+                    Some(on_sort_append) => (on_sort_append)(index, order),
+                    None => (on_sort)(index, order),
+                }
+            });
+
+            // Sorting takes plain clicks, leaving the clicks selecting columns to the
+            // command modifier
+            match (on_select, on_sort) {
+                (Some(on_select), Some(on_sort)) => {
+                    clickable = clickable.on_press(move |modifiers: keyboard::Modifiers| {
+                        if modifiers.command() {
+                            on_select(modifiers)
+                        } else {
+                            on_sort(modifiers)
+                        }
+                    });
+                }
+                (Some(on_select), None) => clickable = clickable.on_press(on_select),
+                (None, Some(on_sort)) => clickable = clickable.on_press(on_sort),
+                (None, None) => {}
+            }
+
+            if let Some((on_right_click, right_press)) = on_right_click {
+                clickable =
+                    clickable.on_right_press(move || (on_right_click)(index, right_press.get()));
+            }
+
+            clickable.into()
+        } else {
+            content
+        };
+
+        let content = match column.header_tooltip(index) {
+            Some(tooltip) => Tooltip::new(
+                content,
+                tooltip,
+                tooltip_position,
+                tooltip_delay,
+                class.clone(),
+            )
+            .into(),
+            None => content,
+        };
 
         with_divider(
             index,
+            position,
             column,
+            width,
             content,
-            on_drag,
-            on_release,
+            on_resize,
+            on_autofit,
+            on_reset,
+            resizing,
             min_column_width,
             divider_width,
-            style,
+            divider_hit_area,
+            cell_spacing,
+            resize_step,
+            neighbor,
+            class,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn body_container<'a, Column, Row, Message, Theme, Renderer>(
         col_index: usize,
         row_index: usize,
         column: &'a Column,
+        width: f32,
         row: &'a Row,
-        min_column_width: f32,
+        branch: Option<Element<'a, Message, Theme, Renderer>>,
+        is_focused: bool,
+        on_focus: Option<Rc<dyn Fn(usize, usize) -> Message + 'a>>,
+        is_editing: bool,
+        on_edit: Option<Rc<dyn Fn(CellEdit) -> Message + 'a>>,
+        on_click: Option<Rc<dyn Fn(usize, usize) -> Message + 'a>>,
+        on_row_click: Option<Rc<dyn Fn(usize) -> Message + 'a>>,
+        on_row_double_click: Option<Rc<dyn Fn(usize) -> Message + 'a>>,
         divider_width: f32,
+        cell_spacing: f32,
         cell_padding: Padding,
+        clip_cells: bool,
+        gridlines: bool,
+        tooltip_position: tooltip::Position,
+        tooltip_delay: Duration,
+        class: style::Shared<'a, Theme>,
     ) -> Element<'a, Message, Theme, Renderer>
     where
-        Renderer: iced_core::Renderer + 'a,
+        Renderer: iced_core::text::Renderer + 'a,
         Theme: style::Catalog + container::Catalog + 'a,
         Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let width = column.width() + column.resize_offset().unwrap_or_default();
+        let is_editing = is_editing && on_edit.is_some() && column.is_editable();
+        let cell_style = column.cell_style(col_index, row_index, row);
+        let cell_tooltip = column.cell_tooltip(col_index, row_index, row);
 
-        let content = container(column.cell(col_index, row_index, row))
+        let is_plain = column.is_plain()
+            && branch.is_none()
+            && !is_editing
+            && !is_focused
+            && !column.is_selected()
+            && cell_style.is_none()
+            && cell_tooltip.is_none()
+            && on_focus.is_none()
+            && on_click.is_none()
+            && !(on_edit.is_some() && column.is_editable());
+
+        if is_plain {
+            let cell = Plain::new(
+                column
+                    .cell_text(col_index, row_index, row)
+                    .unwrap_or_default(),
+                width,
+                cell_padding,
+                cell_spacing,
+            );
+
+            return if gridlines {
+                style::wrapper::gridline(cell, class, divider_width)
+            } else {
+                cell.into()
+            };
+        }
+
+        let cell = if is_editing {
+            column.edit_cell(col_index, row_index, row)
+        } else {
+            column.cell(col_index, row_index, row)
+        };
+
+        let cell = match branch {
+            Some(branch) => row![branch, cell]
+                .align_y(alignment::Vertical::Center)
+                .into(),
+            None => cell,
+        };
+
+        let content = container(cell)
             .width(Length::Fill)
-            .padding(cell_padding);
+            .padding(cell_padding)
+            .clip(clip_cells);
 
-        let spacing = Space::new(divider_width, Length::Shrink);
+        let content = match cell_style {
+            Some(cell_style) => style::wrapper::cell(content, class.clone(), cell_style),
+            None => content.into(),
+        };
 
-        row![content, spacing]
-            .width(width.max(min_column_width))
-            .into()
+        let content = if column.is_selected() {
+            style::wrapper::selected(content, class.clone())
+        } else {
+            content
+        };
+
+        let content = if is_editing {
+            style::wrapper::editing(content, class.clone())
+        } else {
+            content
+        };
+
+        let content = if is_focused {
+            style::wrapper::focused(content, class.clone())
+        } else {
+            content
+        };
+
+        let on_edit = on_edit.filter(|_| column.is_editable() && !is_editing);
+
+        let content = if on_focus.is_some() || on_edit.is_some() || on_click.is_some() {
+            let mut clickable = Clickable::new(content);
+
+            if let Some(on_focus) = on_focus {
+                clickable = clickable.on_press(move |_| (on_focus)(row_index, col_index));
+            }
+
+            if let Some(on_click) = on_click {
+                clickable = clickable.on_press(move |_| (on_click)(row_index, col_index));
+            }
+
+            if let Some(on_edit) = on_edit {
+                clickable = clickable.on_double_press(move || {
+                    (on_edit)(CellEdit::Start {
+                        row: row_index,
+                        column: col_index,
+                    })
+                });
+            }
+
+            // The cell captures the click, so it produces the messages of the row as well
+            if let Some(on_row_click) = on_row_click {
+                clickable = clickable.on_press(move |_| (on_row_click)(row_index));
+            }
+
+            if let Some(on_row_double_click) = on_row_double_click {
+                clickable = clickable.on_double_press(move || (on_row_double_click)(row_index));
+            }
+
+            clickable.into()
+        } else {
+            content
+        };
+
+        let content = match cell_tooltip {
+            Some(tooltip) => Tooltip::new(
+                content,
+                tooltip,
+                tooltip_position,
+                tooltip_delay,
+                class.clone(),
+            )
+            .into(),
+            None => content,
+        };
+
+        let spacing = Space::new(cell_spacing, Length::Shrink);
+        let cell = row![content, spacing].width(width);
+
+        if gridlines {
+            style::wrapper::gridline(cell, class, divider_width)
+        } else {
+            cell.into()
+        }
+    }
+
+    // The indentation & toggle in front of the first cell of a row of a tree, where
+    // "expanded" is set for rows with children
+    fn tree_branch<'a, Message, Theme, Renderer>(
+        row_index: usize,
+        depth: usize,
+        expanded: Option<bool>,
+        on_toggle: Rc<dyn Fn(usize, bool) -> Message + 'a>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: text::Catalog + 'a,
+        Message: 'a,
+    {
+        const INDENT: f32 = 16.0;
+
+        let toggle: Element<'a, Message, Theme, Renderer> = match expanded {
+            Some(expanded) => Clickable::new(text(if expanded { "▾" } else { "▸" }).width(INDENT))
+                .on_press(move |_| (on_toggle)(row_index, !expanded))
+                .into(),
+            None => Space::with_width(INDENT).into(),
+        };
+
+        row![Space::with_width(depth as f32 * INDENT), toggle].into()
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn footer_container<'a, Column, Row, Message, Theme, Renderer>(
         index: usize,
+        position: usize,
         column: &'a Column,
-        rows: &'a [Row],
-        on_drag: Option<fn(usize, f32) -> Message>,
-        on_release: Option<Message>,
+        width: f32,
+        displayed: Displayed<'a, '_, Row>,
+        on_resize: Option<&OnResize<'a, Message>>,
+        on_autofit: Option<&dyn Fn(usize) -> Message>,
+        on_reset: Option<&dyn Fn(usize) -> Message>,
+        resizing: Resizing,
         min_column_width: f32,
         divider_width: f32,
+        divider_hit_area: f32,
+        cell_spacing: f32,
+        resize_step: Option<f32>,
+        neighbor: Option<(usize, &'a Column, f32)>,
         cell_padding: Padding,
-        style: <Theme as style::Catalog>::Style,
+        clip_cells: bool,
+        class: style::Shared<'a, Theme>,
     ) -> Element<'a, Message, Theme, Renderer>
     where
         Renderer: iced_core::Renderer + 'a,
@@ -393,36 +3480,60 @@ pub mod table {
         Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let content = if let Some(footer) = column.footer(index, rows) {
+        let content = if let Some(footer) = column.displayed_footer(index, displayed) {
             container(footer)
                 .width(Length::Fill)
                 .padding(cell_padding)
+                .clip(clip_cells)
                 .into()
         } else {
             Element::from(Space::with_width(Length::Fill))
         };
 
+        let content = if column.is_selected() {
+            style::wrapper::selected(content, class.clone())
+        } else {
+            content
+        };
+
         with_divider(
             index,
+            position,
             column,
+            width,
             content,
-            on_drag,
-            on_release,
+            on_resize,
+            on_autofit,
+            on_reset,
+            resizing,
             min_column_width,
             divider_width,
-            style,
+            divider_hit_area,
+            cell_spacing,
+            resize_step,
+            neighbor,
+            class,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn with_divider<'a, Column, Row, Message, Theme, Renderer>(
         index: usize,
+        position: usize,
         column: &'a Column,
+        width: f32,
         content: Element<'a, Message, Theme, Renderer>,
-        on_drag: Option<fn(usize, f32) -> Message>,
-        on_release: Option<Message>,
+        on_resize: Option<&OnResize<'a, Message>>,
+        on_autofit: Option<&dyn Fn(usize) -> Message>,
+        on_reset: Option<&dyn Fn(usize) -> Message>,
+        resizing: Resizing,
         min_column_width: f32,
         divider_width: f32,
-        style: <Theme as style::Catalog>::Style,
+        divider_hit_area: f32,
+        cell_spacing: f32,
+        resize_step: Option<f32>,
+        neighbor: Option<(usize, &'a Column, f32)>,
+        class: style::Shared<'a, Theme>,
     ) -> Element<'a, Message, Theme, Renderer>
     where
         Renderer: iced_core::Renderer + 'a,
@@ -430,35 +3541,503 @@ pub mod table {
         Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let width =
-            (column.width() + column.resize_offset().unwrap_or_default()).max(min_column_width);
+        let width = clamp_width(column, width, min_column_width);
+        let neighbor = neighbor.map(|(next_index, next, next_width)| {
+            (
+                next_index,
+                next,
+                clamp_width(next, next_width, min_column_width),
+            )
+        });
 
-        if let Some((on_drag, on_release)) = on_drag.zip(on_release) {
-            let old_width = column.width();
-
-            container(Divider::new(
+        if let Some(on_resize) = on_resize {
+            let divider = Divider::new(
                 content,
                 divider_width,
+                divider_hit_area,
+                position,
+                resizing,
                 move |offset| {
-                    let new_width = (old_width + offset).max(min_column_width);
+                    let resized = match resize_step {
+                        Some(step) => ((width + offset) / step).round() * step,
+                        None => width + offset,
+                    };
+
+                    let offset = clamp_width(column, resized, min_column_width) - width;
 
-                    (on_drag)(index, new_width - old_width)
+                    // Within the limits of both columns, as the neighbor shrinks as much
+                    match neighbor {
+                        Some((_, next, next_width)) => {
+                            next_width - clamp_width(next, next_width - offset, min_column_width)
+                        }
+                        None => offset,
+                    }
                 },
-                on_release,
-                style,
-            ))
-            .width(width)
-            .into()
+                {
+                    let on_resize = on_resize.clone();
+
+                    move |offset| (on_resize)(index, width + offset)
+                },
+                on_autofit.map(|on_autofit| (on_autofit)(index)),
+                match on_reset {
+                    Some(on_reset) => Some((on_reset)(index)),
+                    None => column
+                        .default_width()
+                        .map(|default_width| (on_resize)(index, default_width)),
+                },
+                class,
+            );
+
+            let divider = match neighbor {
+                Some((next_index, _, next_width)) => {
+                    let on_resize = on_resize.clone();
+
+                    divider.neighbor(move |offset| (on_resize)(next_index, next_width - offset))
+                }
+                None => divider,
+            };
+
+            container(divider).width(width).into()
         } else {
-            row![content, Space::new(divider_width, Length::Shrink)]
+            row![content, Space::new(cell_spacing, Length::Shrink)]
                 .width(width)
                 .into()
         }
     }
 
+    fn error_container<'a, Message, Theme, Renderer>(
+        range: Range<usize>,
+        on_retry: Option<Rc<dyn Fn(Range<usize>) -> Message + 'a>>,
+        width: f32,
+        cell_padding: Padding,
+        class: style::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog + container::Catalog + text::Catalog + button::Catalog + 'a,
+        Message: 'a + Clone,
+    {
+        let label = match range.len() {
+            1 => "Failed to load 1 row".to_string(),
+            count => format!("Failed to load {count} rows"),
+        };
+
+        let content = row![
+            text(label).width(Length::Fill),
+            button(text("Retry")).on_press_maybe(on_retry.map(|on_retry| (on_retry)(range))),
+        ]
+        .spacing(8)
+        .align_y(alignment::Vertical::Center);
+
+        style::wrapper::error(container(content).width(width).padding(cell_padding), class)
+    }
+
+    // A row spanning the full width of the table
+    #[allow(clippy::too_many_arguments)]
+    fn full_width_container<'a, Column, Row, Message, Theme, Renderer>(
+        col_index: usize,
+        row_index: usize,
+        column: &'a Column,
+        row: &'a Row,
+        width: f32,
+        height: Option<f32>,
+        cell_padding: Padding,
+        class: style::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog + container::Catalog + 'a,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a,
+    {
+        let content = container(column.cell(col_index, row_index, row))
+            .width(width)
+            .height(height.map_or(Length::Shrink, Length::Fixed))
+            .padding(cell_padding)
+            .align_y(alignment::Vertical::Center);
+
+        style::wrapper::full_width_row(content, class)
+    }
+
+    // The headers of the groups, above the headers of the columns, each spanning a run of
+    // adjacent displayed columns in the same group
+    #[allow(clippy::too_many_arguments)]
+    fn group_band<'a, Column, Row, Message, Theme, Renderer>(
+        columns: &'a [Column],
+        shown: &[usize],
+        widths: &[f32],
+        min_width: f32,
+        min_column_width: f32,
+        cell_spacing: f32,
+        cell_padding: Padding,
+        clip_cells: bool,
+        align_y: alignment::Vertical,
+        frozen_columns: usize,
+        resizing: Resizing,
+        debug: DebugMode,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog + container::Catalog + text::Catalog + 'a,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        // The runs of positions within the displayed columns, never crossing the frozen ones
+        let mut runs: Vec<Range<usize>> = Vec::new();
+
+        for (position, &index) in shown.iter().enumerate() {
+            match runs.last_mut() {
+                Some(run)
+                    if position != frozen_columns
+                        && columns[shown[run.start]].group() == columns[index].group() =>
+                {
+                    run.end = position + 1;
+                }
+                _ => runs.push(position..position + 1),
+            }
+        }
+
+        let frozen = runs.iter().filter(|run| run.start < frozen_columns).count();
+
+        let cells = runs.clone().into_iter().map(|run| {
+            let width: f32 = shown[run.clone()]
+                .iter()
+                .map(|&index| clamp_width(&columns[index], widths[index], min_column_width))
+                .sum();
+
+            let content = if let Some(group) = columns[shown[run.start]].group() {
+                container(text(group))
+                    .width(Length::Fill)
+                    .align_x(alignment::Horizontal::Center)
+                    .padding(cell_padding)
+                    .clip(clip_cells)
+                    .into()
+            } else {
+                Element::from(Space::with_width(Length::Fill))
+            };
+
+            let cell = row![content, Space::new(cell_spacing, Length::Shrink)]
+                .width(width)
+                .into();
+
+            (cell, align_y)
+        });
+
+        Cells::new(cells)
+            .push_maybe(dummy_container(
+                columns,
+                widths,
+                min_width,
+                min_column_width,
+            ))
+            .frozen(frozen)
+            .resizing(resizing)
+            .spans(runs)
+            .debug(debug)
+            .into()
+    }
+
+    // A placeholder row, with a placeholder in each displayed column
+    #[allow(clippy::too_many_arguments)]
+    fn skeleton_row<'a, Column, Row, Message, Theme, Renderer>(
+        columns: &'a [Column],
+        shown: &[usize],
+        widths: &[f32],
+        min_width: f32,
+        min_column_width: f32,
+        cell_spacing: f32,
+        cell_padding: Padding,
+        frozen_columns: usize,
+        resizing: Resizing,
+        class: style::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog + container::Catalog + 'a,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let cells = shown.iter().map(|&index| {
+            let column = &columns[index];
+
+            let content = container(Skeleton::new(class.clone()))
+                .width(Length::Fill)
+                .padding(cell_padding);
+
+            let cell = row![content, Space::new(cell_spacing, Length::Shrink)]
+                .width(clamp_width(column, widths[index], min_column_width))
+                .into();
+
+            (cell, alignment::Vertical::Center)
+        });
+
+        Cells::new(cells)
+            .push_maybe(dummy_container(
+                columns,
+                widths,
+                min_width,
+                min_column_width,
+            ))
+            .frozen(frozen_columns)
+            .resizing(resizing)
+            .into()
+    }
+
+    // Splits the displayed rows into groups of consecutive rows, leaving out the rows of the
+    // collapsed groups
+    fn group_rows<Row>(
+        rows: &Rows<'_, Row>,
+        displayed: Vec<usize>,
+        same_group: &dyn Fn(&Row, &Row) -> bool,
+        is_expanded: &dyn Fn(&Row) -> bool,
+    ) -> (Vec<usize>, Vec<RowGroup>) {
+        let mut shown = Vec::with_capacity(displayed.len());
+        let mut groups: Vec<RowGroup> = Vec::new();
+
+        for index in displayed {
+            let is_same_group = match groups.last().and_then(|group| group.rows.last()) {
+                Some(&previous) => (same_group)(rows.get(previous), rows.get(index)),
+                None => false,
+            };
+
+            if !is_same_group {
+                groups.push(RowGroup {
+                    rows: Vec::new(),
+                    start: shown.len(),
+                    expanded: (is_expanded)(rows.get(index)),
+                });
+            }
+
+            let group = groups.last_mut().expect("row has a group");
+
+            group.rows.push(index);
+
+            if group.expanded {
+                shown.push(index);
+            }
+        }
+
+        (shown, groups)
+    }
+
+    // The header of a group of rows, with the toggle in front of the first cell
+    #[allow(clippy::too_many_arguments)]
+    fn row_group_band<'a, Column, Row, Message, Theme, Renderer>(
+        columns: &'a [Column],
+        widths: &[f32],
+        group: Displayed<'a, '_, Row>,
+        expanded: bool,
+        on_toggle: Rc<dyn Fn(usize, bool) -> Message + 'a>,
+        min_width: f32,
+        min_column_width: f32,
+        cell_spacing: f32,
+        cell_padding: Padding,
+        clip_cells: bool,
+        align_y: alignment::Vertical,
+        frozen_columns: usize,
+        resizing: Resizing,
+        debug: DebugMode,
+        class: style::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog + container::Catalog + text::Catalog + 'a,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let first_row = group.indices()[0];
+
+        let cells = columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| column.is_visible())
+            .enumerate()
+            .map(|(position, (index, column))| {
+                let content = column
+                    .group_header(index, group)
+                    .unwrap_or_else(|| Space::with_width(Length::Fill).into());
+
+                let content = if position == 0 {
+                    row![
+                        tree_branch(first_row, 0, Some(expanded), on_toggle.clone()),
+                        content
+                    ]
+                    .align_y(alignment::Vertical::Center)
+                    .into()
+                } else {
+                    content
+                };
+
+                let cell = row![
+                    container(content)
+                        .width(Length::Fill)
+                        .padding(cell_padding)
+                        .clip(clip_cells),
+                    Space::new(cell_spacing, Length::Shrink)
+                ]
+                .width(clamp_width(column, widths[index], min_column_width))
+                .into();
+
+                (cell, column.align_y().unwrap_or(align_y))
+            });
+
+        style::wrapper::row_group(
+            Cells::new(cells)
+                .push_maybe(dummy_container(
+                    columns,
+                    widths,
+                    min_width,
+                    min_column_width,
+                ))
+                .frozen(frozen_columns)
+                .resizing(resizing)
+                .debug(debug),
+            class,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn summary_band<'a, Column, Row, Message, Theme, Renderer>(
+        columns: &'a [Column],
+        widths: &[f32],
+        level: SummaryLevel,
+        displayed: Displayed<'a, '_, Row>,
+        min_width: f32,
+        min_column_width: f32,
+        cell_spacing: f32,
+        cell_padding: Padding,
+        clip_cells: bool,
+        align_y: alignment::Vertical,
+        frozen_columns: usize,
+        resizing: Resizing,
+        debug: DebugMode,
+        class: style::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::text::Renderer + 'a,
+        Theme: style::Catalog + container::Catalog + 'a,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let cells = columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| column.is_visible())
+            .map(|(index, column)| {
+                let content = if let Some(summary) = column.summary(index, level, displayed) {
+                    container(summary)
+                        .width(Length::Fill)
+                        .padding(cell_padding)
+                        .clip(clip_cells)
+                        .into()
+                } else {
+                    Element::from(Space::with_width(Length::Fill))
+                };
+
+                let cell = row![content, Space::new(cell_spacing, Length::Shrink)]
+                    .width(clamp_width(column, widths[index], min_column_width))
+                    .into();
+
+                (cell, column.align_y().unwrap_or(align_y))
+            });
+
+        style::wrapper::summary(
+            Cells::new(cells)
+                .push_maybe(dummy_container(
+                    columns,
+                    widths,
+                    min_width,
+                    min_column_width,
+                ))
+                .frozen(frozen_columns)
+                .resizing(resizing)
+                .debug(debug),
+            class,
+            level,
+        )
+    }
+
+    // The width of each column, without any on-going resize. The space left by the other
+    // columns up to "min_width" is shared among the "FillPortion" columns
+    fn column_widths<'a, Column, Row, Message, Theme, Renderer>(
+        columns: &'a [Column],
+        min_width: f32,
+        min_column_width: f32,
+    ) -> Vec<f32>
+    where
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+    {
+        let mut taken = 0.0;
+        let mut portions = 0;
+
+        for column in columns.iter().filter(|column| column.is_visible()) {
+            let width = match column.sizing() {
+                ColumnWidth::Fixed(width) => width,
+                ColumnWidth::Shrink => clamp_width(column, 0.0, min_column_width),
+                ColumnWidth::FillPortion(portion) => {
+                    portions += u32::from(portion);
+                    continue;
+                }
+            };
+
+            taken += clamp_width(column, width, min_column_width);
+        }
+
+        let remaining = (min_width - taken).max(0.0);
+
+        columns
+            .iter()
+            .map(|column| match column.sizing() {
+                ColumnWidth::Fixed(width) => width,
+                ColumnWidth::Shrink => clamp_width(column, 0.0, min_column_width),
+                ColumnWidth::FillPortion(portion) if portions > 0 => {
+                    remaining * f32::from(portion) / portions as f32
+                }
+                ColumnWidth::FillPortion(_) => 0.0,
+            })
+            .collect()
+    }
+
+    // Clamps the width of the column between its minimum & maximum width
+    fn clamp_width<'a, Column, Row, Message, Theme, Renderer>(
+        column: &Column,
+        width: f32,
+        min_column_width: f32,
+    ) -> f32
+    where
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+    {
+        let width = width.max(column.min_width().unwrap_or(min_column_width));
+
+        match column.max_width() {
+            Some(max_width) => width.min(max_width),
+            None => width,
+        }
+    }
+
+    // The width of the columns, enforcing "min_width"
+    fn total_width<'a, Column, Row, Message, Theme, Renderer>(
+        columns: &'a [Column],
+        widths: &[f32],
+        min_width: f32,
+        min_column_width: f32,
+    ) -> f32
+    where
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+    {
+        columns
+            .iter()
+            .zip(widths)
+            .filter(|(column, _)| column.is_visible())
+            .map(|(column, width)| clamp_width(column, *width, min_column_width))
+            .sum::<f32>()
+            .max(min_width)
+    }
+
     // Used to enforce "min_width"
     fn dummy_container<'a, Column, Row, Message, Theme, Renderer>(
         columns: &'a [Column],
+        widths: &[f32],
         min_width: f32,
         min_column_width: f32,
     ) -> Option<Element<'a, Message, Theme, Renderer>>
@@ -470,9 +4049,9 @@ pub mod table {
     {
         let total_width: f32 = columns
             .iter()
-            .map(|column| {
-                (column.width() + column.resize_offset().unwrap_or_default()).max(min_column_width)
-            })
+            .zip(widths)
+            .filter(|(column, _)| column.is_visible())
+            .map(|(column, width)| clamp_width(column, *width, min_column_width))
             .sum();
 
         let remaining = min_width - total_width;
@@ -0,0 +1,196 @@
+use std::rc::Rc;
+
+use iced_core::keyboard::{self, key};
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, Clipboard, Shell};
+use iced_core::{Element, Length, Rectangle, Size, Vector};
+
+/// Moves the focused cell with the arrow keys, unless the content captures them.
+///
+/// `displayed` & `columns` are the indices of the displayed rows & columns, in display
+/// order, which the focus moves through.
+///
+/// The wrapper is transparent: its widget tree is the one of its content.
+pub(crate) struct Navigator<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    displayed: Vec<usize>,
+    columns: Vec<usize>,
+    focused: Option<(usize, usize)>,
+    on_focus: Rc<dyn Fn(usize, usize) -> Message + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Navigator<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        displayed: Vec<usize>,
+        columns: Vec<usize>,
+        focused: Option<(usize, usize)>,
+        on_focus: Rc<dyn Fn(usize, usize) -> Message + 'a>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            displayed,
+            columns,
+            focused,
+            on_focus,
+        }
+    }
+
+    // The cell the focus moves to from the focused cell, if it moves
+    fn next(&self, key: &keyboard::Key) -> Option<(usize, usize)> {
+        let (row, column) = self.focused?;
+        let row = self.displayed.iter().position(|index| *index == row)?;
+        let column = self.columns.iter().position(|index| *index == column)?;
+
+        let (row, column) = match key {
+            keyboard::Key::Named(key::Named::ArrowUp) => (row.checked_sub(1)?, column),
+            keyboard::Key::Named(key::Named::ArrowDown) => (row + 1, column),
+            keyboard::Key::Named(key::Named::ArrowLeft) => (row, column.checked_sub(1)?),
+            keyboard::Key::Named(key::Named::ArrowRight) => (row, column + 1),
+            _ => return None,
+        };
+
+        Some((*self.displayed.get(row)?, *self.columns.get(column)?))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Navigator<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            tree,
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let event::Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event {
+            if let Some((row, column)) = self.next(&key) {
+                shell.publish((self.on_focus)(row, column));
+
+                return event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Navigator<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(navigator: Navigator<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(navigator)
+    }
+}
@@ -0,0 +1,266 @@
+//! Operate on a [`Table`](crate::Table) by its [`Id`], with
+//! [`iced::advanced::widget::operate`](https://docs.rs/iced/0.13/iced/advanced/widget/fn.operate.html).
+use std::any::Any;
+
+use iced_core::widget::operation::scrollable::AbsoluteOffset;
+use iced_core::widget::operation::Outcome;
+use iced_core::widget::{self, Operation};
+use iced_core::{Rectangle, Size};
+
+use crate::table::Id;
+use crate::{bands, root};
+
+/// Copies the text of the column at `index` of the [`Table`](crate::Table) with the given
+/// [`Id`] to the clipboard, one displayed row per line.
+///
+/// The column is copied on the next event reaching the table, such as the redraw following
+/// the operation, producing the message of
+/// [`Table::on_column_copied`](crate::Table::on_column_copied) with its text. Nothing is
+/// copied for an index out of range. The text of each cell is returned by
+/// [`Column::cell_text`](crate::table::Column::cell_text).
+pub fn copy_column<T>(id: Id, index: usize) -> impl Operation<T> {
+    struct CopyColumn {
+        id: Id,
+        index: usize,
+    }
+
+    impl<T> Operation<T> for CopyColumn {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id != Some(&self.id.0) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<root::State>() {
+                state.copy_column = Some(self.index);
+            }
+        }
+    }
+
+    CopyColumn { id, index }
+}
+
+/// Measures the content of the columns of the [`Table`](crate::Table) with the given [`Id`],
+/// returning the width fitting each column to its header & displayed cells.
+///
+/// The widths are indexed by column, each fitting its header & the cells of the displayed
+/// rows along with the [`cell_padding`](crate::Table::cell_padding), within the limits of
+/// the column.
+pub fn fit_columns(id: Id) -> impl Operation<Vec<f32>> {
+    struct FitColumns {
+        id: Id,
+        widths: Option<Vec<f32>>,
+    }
+
+    impl Operation<Vec<f32>> for FitColumns {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Vec<f32>>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id != Some(&self.id.0) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<root::State>() {
+                state.fit_columns = true;
+            } else if let Some(fitted) = state.downcast_mut::<root::FittedColumns>() {
+                self.widths = Some(std::mem::take(&mut fitted.0));
+            }
+        }
+
+        fn finish(&self) -> Outcome<Vec<f32>> {
+            match &self.widths {
+                Some(widths) => Outcome::Some(widths.clone()),
+                None => Outcome::None,
+            }
+        }
+    }
+
+    FitColumns { id, widths: None }
+}
+
+/// Reads the rendered width of each column of the [`Table`](crate::Table) with the given
+/// [`Id`], such as to persist the layout.
+///
+/// The widths are clamped to the minimum & maximum width of each column and include the
+/// on-going resize, if any. Hidden columns keep the width they would be displayed with.
+pub fn column_widths(id: Id) -> impl Operation<Vec<f32>> {
+    struct ColumnWidths {
+        id: Id,
+        widths: Option<Vec<f32>>,
+    }
+
+    impl Operation<Vec<f32>> for ColumnWidths {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Vec<f32>>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id != Some(&self.id.0) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_ref::<root::State>() {
+                self.widths = Some(state.column_widths.clone());
+            }
+        }
+
+        fn finish(&self) -> Outcome<Vec<f32>> {
+            match &self.widths {
+                Some(widths) => Outcome::Some(widths.clone()),
+                None => Outcome::None,
+            }
+        }
+    }
+
+    ColumnWidths { id, widths: None }
+}
+
+/// How the body of a [`Table`](crate::Table) is scrolled, as read by [`scroll_offset`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollOffset {
+    /// The offset of the content of the body.
+    pub offset: AbsoluteOffset,
+    /// The size of the content of the body.
+    pub content_size: Size,
+}
+
+/// Reads how the body of the [`Table`](crate::Table) with the given [`Id`] is scrolled, such
+/// as to restore it later with [`scroll_to`].
+pub fn scroll_offset(id: Id) -> impl Operation<ScrollOffset> {
+    struct ReadScrollOffset {
+        id: Id,
+        // Whether the next bands are those of the table
+        is_target: bool,
+        scroll: Option<ScrollOffset>,
+    }
+
+    impl Operation<ScrollOffset> for ReadScrollOffset {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<ScrollOffset>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if state.is::<root::State>() {
+                self.is_target = id == Some(&self.id.0);
+            } else if let Some(state) = state.downcast_ref::<bands::State>() {
+                if std::mem::take(&mut self.is_target) {
+                    self.scroll = state.scroll;
+                }
+            }
+        }
+
+        fn finish(&self) -> Outcome<ScrollOffset> {
+            match self.scroll {
+                Some(scroll) => Outcome::Some(scroll),
+                None => Outcome::None,
+            }
+        }
+    }
+
+    ReadScrollOffset {
+        id,
+        is_target: false,
+        scroll: None,
+    }
+}
+
+/// Scrolls the body of the [`Table`](crate::Table) with the given [`Id`] to the given offset,
+/// such as one read by [`scroll_offset`].
+pub fn scroll_to<T>(id: Id, offset: AbsoluteOffset) -> impl Operation<T> {
+    struct ScrollTo {
+        id: Id,
+        offset: AbsoluteOffset,
+        // Whether the next bands are those of the table
+        is_target: bool,
+    }
+
+    impl<T> Operation<T> for ScrollTo {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if state.is::<root::State>() {
+                self.is_target = id == Some(&self.id.0);
+            } else if let Some(state) = state.downcast_mut::<bands::State>() {
+                if std::mem::take(&mut self.is_target) {
+                    state.scroll_to = Some(self.offset);
+                }
+            }
+        }
+    }
+
+    ScrollTo {
+        id,
+        offset,
+        is_target: false,
+    }
+}
+
+/// Focuses the first focusable widget, such as a text input, of the body cell at `row` &
+/// `column` of the [`Table`](crate::Table) with the given [`Id`], unfocusing the others.
+///
+/// From there, Tab & Shift+Tab move the focus through the focusable widgets of the cells,
+/// in row-major order.
+pub fn focus_cell<T>(id: Id, row: usize, column: usize) -> impl Operation<T> {
+    struct FocusCell {
+        id: Id,
+        cell: (usize, usize),
+    }
+
+    impl<T> Operation<T> for FocusCell {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id != Some(&self.id.0) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<root::State>() {
+                state.focus_cell = Some(self.cell);
+            }
+        }
+    }
+
+    FocusCell {
+        id,
+        cell: (row, column),
+    }
+}
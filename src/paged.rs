@@ -0,0 +1,192 @@
+//! Display rows fetched page by page from a [`DataSource`], such as a database or an HTTP
+//! API, as they are scrolled into view.
+//!
+//! The rows are kept in a [`Paged`] cache, displayed with
+//! [`table_paged`](crate::table::table_paged). The rows which aren't loaded yet are shown
+//! as placeholders. As the [`virtualize`](crate::Table::virtualize)d body is scrolled, the
+//! pages of the rows reported by [`on_rows_visible`](crate::Table::on_rows_visible),
+//! including the overscan rows just out of view, are requested and fetched by the
+//! application, such as with a `Task`:
+//!
+//! ```no_run
+//! # use std::future::Future;
+//! # use std::ops::Range;
+//! # use iced_table::paged::{DataSource, Paged};
+//! # use iced_widget::runtime::Task;
+//! # struct Source;
+//! # impl DataSource for Source {
+//! #     type Row = String;
+//! #     fn row_count(&self) -> usize { 0 }
+//! #     fn fetch(&self, _: Range<usize>) -> impl Future<Output = Vec<String>> + Send + 'static {
+//! #         async { Vec::new() }
+//! #     }
+//! # }
+//! # #[derive(Debug, Clone)]
+//! # enum Message { RowsVisible(Range<usize>), Fetched(usize, Vec<String>) }
+//! # struct App { rows: Paged<String>, source: Source }
+//! # impl App {
+//! # fn update(&mut self, message: Message) -> Task<Message> {
+//! # match message {
+//! Message::RowsVisible(rows) => {
+//!     let pages = self.rows.request(rows);
+//!
+//!     Task::batch(pages.into_iter().map(|range| {
+//!         Task::perform(self.source.fetch(range.clone()), move |rows| {
+//!             Message::Fetched(range.start, rows)
+//!         })
+//!     }))
+//! }
+//! Message::Fetched(start, rows) => {
+//!     self.rows.insert(start, rows);
+//!     Task::none()
+//! }
+//! # }
+//! # }
+//! # }
+//! ```
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::ops::Range;
+
+use iced_widget::scrollable;
+
+/// A source of rows fetched by range, such as a database or an HTTP API.
+pub trait DataSource {
+    /// A row of data.
+    type Row;
+
+    /// Return the total number of rows.
+    fn row_count(&self) -> usize;
+
+    /// Fetch the rows in the range.
+    fn fetch(&self, range: Range<usize>) -> impl Future<Output = Vec<Self::Row>> + Send + 'static;
+}
+
+/// The rows of a [`DataSource`] loaded so far, by page.
+#[derive(Debug, Clone)]
+pub struct Paged<Row> {
+    row_count: usize,
+    page_size: usize,
+    pages: HashMap<usize, Vec<Row>>,
+    requested: HashSet<usize>,
+    placeholder: Row,
+}
+
+impl<Row: Default> Paged<Row> {
+    /// Creates a new [`Paged`] cache of `row_count` rows, fetched `page_size` rows at a
+    /// time.
+    ///
+    /// The rows which aren't loaded yet are given to the columns as [`Default`] rows, and
+    /// displayed as placeholders.
+    pub fn new(row_count: usize, page_size: usize) -> Self {
+        Self {
+            row_count,
+            page_size: page_size.max(1),
+            pages: HashMap::new(),
+            requested: HashSet::new(),
+            placeholder: Row::default(),
+        }
+    }
+}
+
+impl<Row> Paged<Row> {
+    /// Returns the total number of rows.
+    pub fn len(&self) -> usize {
+        self.row_count
+    }
+
+    /// Returns whether there are no rows.
+    pub fn is_empty(&self) -> bool {
+        self.row_count == 0
+    }
+
+    /// Returns the row at `index`, if it's loaded.
+    pub fn get(&self, index: usize) -> Option<&Row> {
+        self.pages
+            .get(&(index / self.page_size))
+            .and_then(|page| page.get(index % self.page_size))
+    }
+
+    /// Returns whether the row at `index` is loaded.
+    pub fn is_loaded(&self, index: usize) -> bool {
+        self.get(index).is_some()
+    }
+
+    /// Requests the pages of the rows in the range which weren't requested yet, returning
+    /// the range of rows of each page to fetch.
+    pub fn request(&mut self, rows: Range<usize>) -> Vec<Range<usize>> {
+        let end = rows.end.min(self.row_count);
+
+        if rows.start >= end {
+            return Vec::new();
+        }
+
+        let pages: Vec<usize> = (rows.start / self.page_size..=(end - 1) / self.page_size)
+            .filter(|page| self.requested.insert(*page))
+            .collect();
+
+        pages
+            .into_iter()
+            .map(|page| self.page_range(page))
+            .collect()
+    }
+
+    /// Requests the pages of the rows visible in the [`Viewport`](scrollable::Viewport) of
+    /// the body, given the height of each row, as with [`request`](Self::request).
+    ///
+    /// The viewport is the one reported by [`Table::on_scroll`](crate::Table::on_scroll).
+    pub fn request_visible(
+        &mut self,
+        viewport: scrollable::Viewport,
+        row_height: f32,
+    ) -> Vec<Range<usize>> {
+        let row_height = row_height.max(1.0);
+        let offset_y = viewport.absolute_offset().y;
+
+        let start = (offset_y / row_height) as usize;
+        let end = ((offset_y + viewport.bounds().height) / row_height).ceil() as usize;
+
+        self.request(start..end)
+    }
+
+    /// Inserts the rows fetched for the page starting at row `start`.
+    pub fn insert(&mut self, start: usize, rows: Vec<Row>) {
+        let page = start / self.page_size;
+
+        self.requested.insert(page);
+        self.pages.insert(page, rows);
+    }
+
+    /// Forgets the request of the pages of the rows in the range, such as when fetching
+    /// them failed, so they are requested again.
+    pub fn fail(&mut self, rows: Range<usize>) {
+        if rows.start >= rows.end {
+            return;
+        }
+
+        for page in rows.start / self.page_size..=(rows.end - 1) / self.page_size {
+            if !self.pages.contains_key(&page) {
+                self.requested.remove(&page);
+            }
+        }
+    }
+
+    /// Drops all the loaded rows & requests, such as when the data changed, with the new
+    /// total number of rows.
+    pub fn reset(&mut self, row_count: usize) {
+        self.row_count = row_count;
+        self.pages.clear();
+        self.requested.clear();
+    }
+
+    // The row at `index`, or the placeholder if it isn't loaded
+    pub(crate) fn row(&self, index: usize) -> &Row {
+        self.get(index).unwrap_or(&self.placeholder)
+    }
+
+    fn page_range(&self, page: usize) -> Range<usize> {
+        let start = page * self.page_size;
+
+        start..(start + self.page_size).min(self.row_count)
+    }
+}
@@ -0,0 +1,141 @@
+//! Persist the layout of the columns of a [`Table`](crate::Table) between sessions.
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::table::SortOrder;
+
+/// A column whose layout can be persisted in a [`TableLayout`].
+pub trait Persist {
+    /// Return the key identifying this column across sessions.
+    fn key(&self) -> Cow<'_, str>;
+
+    /// Return the width of this column.
+    fn width(&self) -> f32;
+
+    /// Set the width of this column.
+    fn set_width(&mut self, width: f32);
+
+    /// Return whether this column is displayed.
+    fn is_visible(&self) -> bool {
+        true
+    }
+
+    /// Set whether this column is displayed.
+    fn set_visible(&mut self, _visible: bool) {}
+
+    /// Return the order this column is sorted in, if any.
+    fn sort_order(&self) -> Option<SortOrder> {
+        None
+    }
+
+    /// Set the order this column is sorted in, if any.
+    fn set_sort_order(&mut self, _order: Option<SortOrder>) {}
+}
+
+/// The layout of a column, in a [`TableLayout`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnLayout {
+    /// The [key](Persist::key) of the column.
+    pub key: String,
+    /// The width of the column.
+    pub width: f32,
+    /// Whether the column is displayed.
+    pub visible: bool,
+    /// The order the column is sorted in, if any.
+    pub sort_order: Option<SortOrder>,
+}
+
+/// The order, widths, visibility & sort state of the columns of a [`Table`](crate::Table),
+/// as customized by the user.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TableLayout {
+    /// The layout of each column, in order.
+    pub columns: Vec<ColumnLayout>,
+}
+
+impl TableLayout {
+    /// Captures the layout of the columns.
+    pub fn capture<Column: Persist>(columns: &[Column]) -> Self {
+        Self {
+            columns: columns
+                .iter()
+                .map(|column| ColumnLayout {
+                    key: column.key().into_owned(),
+                    width: column.width(),
+                    visible: column.is_visible(),
+                    sort_order: column.sort_order(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Applies the layout to the columns, reordering them & restoring their width,
+    /// visibility & sort state.
+    ///
+    /// Columns missing from the layout, such as ones added since it was captured, are kept
+    /// as they are after the others.
+    pub fn apply<Column: Persist>(&self, columns: &mut Vec<Column>) {
+        let mut remaining = std::mem::take(columns);
+
+        for layout in &self.columns {
+            if let Some(position) = remaining
+                .iter()
+                .position(|column| column.key() == layout.key.as_str())
+            {
+                let mut column = remaining.remove(position);
+
+                column.set_width(layout.width);
+                column.set_visible(layout.visible);
+                column.set_sort_order(layout.sort_order);
+
+                columns.push(column);
+            }
+        }
+
+        columns.append(&mut remaining);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Column {
+        key: &'static str,
+        width: f32,
+    }
+
+    impl Persist for Column {
+        fn key(&self) -> Cow<'_, str> {
+            self.key.into()
+        }
+
+        fn width(&self) -> f32 {
+            self.width
+        }
+
+        fn set_width(&mut self, width: f32) {
+            self.width = width;
+        }
+    }
+
+    fn column(key: &'static str, width: f32) -> Column {
+        Column { key, width }
+    }
+
+    #[test]
+    fn apply_reorders_and_keeps_new_columns_last() {
+        let layout =
+            TableLayout::capture(&[column("c", 30.0), column("gone", 10.0), column("a", 50.0)]);
+
+        let mut columns = vec![column("a", 100.0), column("b", 100.0), column("c", 100.0)];
+        layout.apply(&mut columns);
+
+        assert_eq!(
+            columns,
+            vec![column("c", 30.0), column("a", 50.0), column("b", 100.0)]
+        );
+    }
+}
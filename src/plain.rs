@@ -0,0 +1,102 @@
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::Cursor;
+use iced_core::text::{self, LineHeight, Shaping, Wrapping};
+use iced_core::widget::{Tree, Widget};
+use iced_core::{alignment, renderer};
+use iced_core::{Element, Length, Padding, Point, Rectangle, Size};
+
+/// A cell of plain text drawn directly with the renderer, without any widget of its own.
+///
+/// It takes the `width` of its column, including the space left on its right,
+/// and is clipped to its padded bounds.
+pub(crate) struct Plain {
+    content: String,
+    width: f32,
+    padding: Padding,
+    spacing: f32,
+}
+
+impl Plain {
+    pub fn new(content: String, width: f32, padding: Padding, spacing: f32) -> Self {
+        Self {
+            content,
+            width,
+            padding,
+            spacing,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Plain
+where
+    Renderer: text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.width), Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let line_height = LineHeight::default().to_absolute(renderer.default_size());
+
+        layout::Node::new(limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(self.width, line_height.0 + self.padding.vertical()),
+        ))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let content = Rectangle {
+            x: bounds.x + self.padding.left,
+            y: bounds.y + self.padding.top,
+            width: (bounds.width - self.padding.horizontal() - self.spacing).max(0.0),
+            height: (bounds.height - self.padding.vertical()).max(0.0),
+        };
+
+        let Some(clip_bounds) = content.intersection(viewport) else {
+            return;
+        };
+
+        renderer.fill_text(
+            text::Text {
+                content: self.content.clone(),
+                bounds: Size::new(content.width, f32::INFINITY),
+                size: renderer.default_size(),
+                line_height: LineHeight::default(),
+                font: renderer.default_font(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: Shaping::Basic,
+                wrapping: Wrapping::None,
+            },
+            Point::new(content.x, content.y),
+            style.text_color,
+            clip_bounds,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Plain> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer + 'a,
+{
+    fn from(plain: Plain) -> Self {
+        Element::new(plain)
+    }
+}
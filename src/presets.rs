@@ -0,0 +1,100 @@
+//! Ready-made [`ColumnDef`]s for common cells, emitting messages with the index of the row.
+use std::rc::Rc;
+
+use iced_core::{text, Length};
+use iced_widget::{button, checkbox, pick_list, Text};
+
+use crate::ColumnDef;
+
+/// Creates a [`ColumnDef`] displaying the text of each row.
+pub fn text_column<Row, Message, Theme, Renderer>(
+    header: impl Into<String>,
+    value: impl Fn(&Row) -> String + 'static,
+) -> ColumnDef<Row, Message, Theme, Renderer>
+where
+    Message: 'static,
+    Theme: iced_widget::text::Catalog + 'static,
+    Renderer: text::Renderer + 'static,
+{
+    ColumnDef::new()
+        .header(header)
+        .cell(move |row| Text::new(value(row)).into())
+}
+
+/// Creates a [`ColumnDef`] with a checkbox in each row, checked when `is_checked`, emitting
+/// `on_toggle` with the index of the row once toggled.
+pub fn checkbox_column<Row, Message, Theme, Renderer>(
+    header: impl Into<String>,
+    is_checked: impl Fn(&Row) -> bool + 'static,
+    on_toggle: impl Fn(usize, bool) -> Message + 'static,
+) -> ColumnDef<Row, Message, Theme, Renderer>
+where
+    Message: 'static,
+    Theme: checkbox::Catalog + iced_widget::text::Catalog + 'static,
+    Renderer: text::Renderer + 'static,
+{
+    let on_toggle = Rc::new(on_toggle);
+
+    ColumnDef::new()
+        .header(header)
+        .indexed_cell(move |row_index, row| {
+            let on_toggle = on_toggle.clone();
+
+            checkbox("", is_checked(row))
+                .on_toggle(move |checked| on_toggle(row_index, checked))
+                .into()
+        })
+}
+
+/// Creates a [`ColumnDef`] with a button labeled `label` in each row, emitting `on_press`
+/// with the index of the row once pressed.
+pub fn button_column<Row, Message, Theme, Renderer>(
+    header: impl Into<String>,
+    label: impl Into<String>,
+    on_press: impl Fn(usize) -> Message + 'static,
+) -> ColumnDef<Row, Message, Theme, Renderer>
+where
+    Message: Clone + 'static,
+    Theme: button::Catalog + iced_widget::text::Catalog + 'static,
+    Renderer: text::Renderer + 'static,
+{
+    let label = label.into();
+
+    ColumnDef::new()
+        .header(header)
+        .indexed_cell(move |row_index, _row| {
+            button(Text::new(label.clone()))
+                .on_press(on_press(row_index))
+                .into()
+        })
+}
+
+/// Creates a [`ColumnDef`] with a pick list of the `options` in each row, showing the
+/// `selected` option of the row & emitting `on_select` with the index of the row once an
+/// option is picked.
+pub fn pick_list_column<T, Row, Message, Theme, Renderer>(
+    header: impl Into<String>,
+    options: &'static [T],
+    selected: impl Fn(&Row) -> Option<T> + 'static,
+    on_select: impl Fn(usize, T) -> Message + 'static,
+) -> ColumnDef<Row, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'static,
+    Message: Clone + 'static,
+    Theme: pick_list::Catalog + iced_widget::overlay::menu::Catalog + 'static,
+    Renderer: text::Renderer + 'static,
+{
+    let on_select = Rc::new(on_select);
+
+    ColumnDef::new()
+        .header(header)
+        .indexed_cell(move |row_index, row| {
+            let on_select = on_select.clone();
+
+            pick_list(options, selected(row), move |option| {
+                on_select(row_index, option)
+            })
+            .width(Length::Fill)
+            .into()
+        })
+}
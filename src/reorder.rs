@@ -0,0 +1,385 @@
+use std::rc::Rc;
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, Clipboard, Shell};
+use iced_core::{Element, Length, Point, Rectangle, Size, Vector};
+
+use crate::style;
+
+// How far the cursor must move before a press turns into a drag
+const DRAG_THRESHOLD: f32 = 4.0;
+
+#[derive(Debug, Default)]
+struct State {
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    // The position of the dragged item among the draggable items
+    from: usize,
+    origin: Point,
+    // The gap the item would be dropped into, once dragged past the threshold
+    gap: Option<usize>,
+}
+
+/// The direction the children of a [`Reorder`] are laid out in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Axis {
+    /// Side by side, such as the header cells.
+    Horizontal,
+    /// Stacked, such as the body rows.
+    Vertical,
+}
+
+impl Axis {
+    fn main(self, point: Point) -> f32 {
+        match self {
+            Axis::Horizontal => point.x,
+            Axis::Vertical => point.y,
+        }
+    }
+
+    // The start & end of the bounds along the axis
+    fn span(self, bounds: Rectangle) -> (f32, f32) {
+        match self {
+            Axis::Horizontal => (bounds.x, bounds.x + bounds.width),
+            Axis::Vertical => (bounds.y, bounds.y + bounds.height),
+        }
+    }
+}
+
+/// Lets the children of the content it wraps, such as the header cells or the body rows,
+/// be dragged to a new position, drawing a drop indicator in the gap under the cursor.
+///
+/// `items` are the indices published for the leading children of the content, in order.
+/// Children without an index can't be dragged, and aren't gaps to drop into.
+pub(crate) struct Reorder<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    axis: Axis,
+    items: Vec<Option<usize>>,
+    on_reorder: Rc<dyn Fn(usize, usize) -> Message + 'a>,
+    class: style::Shared<'a, Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> Reorder<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        axis: Axis,
+        items: Vec<Option<usize>>,
+        on_reorder: Rc<dyn Fn(usize, usize) -> Message + 'a>,
+        class: style::Shared<'a, Theme>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            axis,
+            items,
+            on_reorder,
+            class,
+        }
+    }
+
+    // The index & bounds of the draggable children, in order
+    fn draggable<'b>(
+        &'b self,
+        layout: Layout<'b>,
+    ) -> impl Iterator<Item = (usize, Rectangle)> + 'b {
+        layout
+            .children()
+            .zip(&self.items)
+            .filter_map(|(child, item)| Some(((*item)?, child.bounds())))
+    }
+
+    fn item_at(&self, layout: Layout<'_>, position: Point) -> Option<usize> {
+        self.draggable(layout)
+            .position(|(_, bounds)| bounds.contains(position))
+    }
+
+    // The gap closest to the position, from 0 (before the first item) to the number of
+    // draggable items (after the last)
+    fn gap_at(&self, layout: Layout<'_>, position: Point) -> usize {
+        let position = self.axis.main(position);
+
+        self.draggable(layout)
+            .filter(|(_, bounds)| {
+                let (start, end) = self.axis.span(*bounds);
+
+                (start + end) / 2.0 < position
+            })
+            .count()
+    }
+
+    // The position of the gap along the axis
+    fn gap_position(&self, layout: Layout<'_>, gap: usize) -> f32 {
+        let spans: Vec<(f32, f32)> = self
+            .draggable(layout)
+            .map(|(_, bounds)| self.axis.span(bounds))
+            .collect();
+
+        match spans.get(gap) {
+            Some((start, _)) => *start,
+            None => spans
+                .last()
+                .map_or(self.axis.span(layout.bounds()).0, |(_, end)| *end),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Reorder<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let dragging = tree
+            .state
+            .downcast_ref::<State>()
+            .drag
+            .is_some_and(|drag| drag.gap.is_some());
+
+        match event {
+            event::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if let Some((drag, position)) = state.drag.as_mut().zip(cursor.position()) {
+                    let distance = self.axis.main(position) - self.axis.main(drag.origin);
+
+                    if drag.gap.is_some() || distance.abs() > DRAG_THRESHOLD {
+                        drag.gap = Some(self.gap_at(layout, position));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if let Some(Drag {
+                    from,
+                    gap: Some(gap),
+                    ..
+                }) = state.drag.take()
+                {
+                    // The gaps on either side of the dragged item leave it in place
+                    let to = if gap > from { gap - 1 } else { gap };
+
+                    if to != from {
+                        let items: Vec<usize> =
+                            self.draggable(layout).map(|(item, _)| item).collect();
+
+                        shell.publish((self.on_reorder)(items[from], items[to]));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ if dragging => return event::Status::Captured,
+            _ => {}
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event {
+            // Presses on a divider resize its column instead
+            let is_resizing = self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            ) == mouse::Interaction::ResizingHorizontally;
+
+            let state = tree.state.downcast_mut::<State>();
+
+            state.drag = cursor
+                .position()
+                .filter(|_| !is_resizing)
+                .and_then(|position| {
+                    Some(Drag {
+                        from: self.item_at(layout, position)?,
+                        origin: position,
+                        gap: None,
+                    })
+                });
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some_and(|drag| drag.gap.is_some()) {
+            mouse::Interaction::Grabbing
+        } else {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(gap) = state.drag.and_then(|drag| drag.gap) {
+            let appearance = theme.style(&self.class, style::Status::DropIndicator);
+            let bounds = layout.bounds();
+
+            let thickness = appearance.border.width.max(2.0);
+            let (start, end) = self.axis.span(bounds);
+            let position =
+                (self.gap_position(layout, gap) - thickness / 2.0).clamp(start, end - thickness);
+
+            let indicator = match self.axis {
+                Axis::Horizontal => Rectangle {
+                    x: position,
+                    width: thickness,
+                    ..bounds
+                },
+                Axis::Vertical => Rectangle {
+                    y: position,
+                    height: thickness,
+                    ..bounds
+                },
+            };
+
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: indicator,
+                        ..Default::default()
+                    },
+                    appearance
+                        .background
+                        .unwrap_or_else(|| appearance.border.color.into()),
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Reorder<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: style::Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(reorder: Reorder<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(reorder)
+    }
+}
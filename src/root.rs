@@ -0,0 +1,246 @@
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{clipboard, event, overlay, renderer, Clipboard, Shell};
+use iced_core::{Element, Length, Rectangle, Size, Vector};
+
+use crate::focus::FocusCell;
+use crate::table::Id;
+
+/// The state of a [`Table`](crate::Table) targeted by an [`operation`](crate::operation).
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    pub copy_column: Option<usize>,
+    pub fit_columns: bool,
+    pub column_widths: Vec<f32>,
+    pub focus_cell: Option<(usize, usize)>,
+}
+
+/// The widths requested by [`operation::fit_columns`](crate::operation::fit_columns).
+#[derive(Debug)]
+pub(crate) struct FittedColumns(pub Vec<f32>);
+
+/// Returns the widths fitting the content of each column, measured with the renderer.
+type FittedWidths<'a, Renderer> = Box<dyn Fn(&Renderer) -> Vec<f32> + 'a>;
+
+/// Produces the message of a copied column, from its index & text.
+type OnCopied<'a, Message> = Box<dyn Fn(usize, String) -> Message + 'a>;
+
+/// Exposes the [`State`] of the table to [`operation`](crate::operation)s under its [`Id`],
+/// and carries out the requests they leave in it right away.
+///
+/// The outcome of a request, such as the [`FittedColumns`], is handed back to the operation
+/// under the [`Id`] of the table. A column to copy is written to the clipboard on the next
+/// event instead, as the clipboard is only reachable from there.
+pub(crate) struct Root<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    id: Id,
+    column_text: Box<dyn Fn(usize) -> Option<String> + 'a>,
+    fitted_widths: FittedWidths<'a, Renderer>,
+    column_widths: Box<dyn Fn() -> Vec<f32> + 'a>,
+    on_column_copied: Option<OnCopied<'a, Message>>,
+}
+
+impl<'a, Message, Theme, Renderer> Root<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// `column_text` returns the text copied for the column at the given index, if any,
+    /// `fitted_widths` the widths fitting the content of each column, and `column_widths`
+    /// the rendered width of each column.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        id: Id,
+        column_text: impl Fn(usize) -> Option<String> + 'a,
+        fitted_widths: impl Fn(&Renderer) -> Vec<f32> + 'a,
+        column_widths: impl Fn() -> Vec<f32> + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            id,
+            column_text: Box::new(column_text),
+            fitted_widths: Box::new(fitted_widths),
+            column_widths: Box::new(column_widths),
+            on_column_copied: None,
+        }
+    }
+
+    /// Sets the message produced with the index & text of a column once copied.
+    pub fn on_column_copied(self, on_copied: Option<OnCopied<'a, Message>>) -> Self {
+        Self {
+            on_column_copied: on_copied,
+            ..self
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Root<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.column_widths = (self.column_widths)();
+
+        operation.custom(state, Some(&self.id.0));
+
+        let fit_columns = std::mem::take(&mut state.fit_columns);
+        let focus_cell = state.focus_cell.take();
+
+        if fit_columns {
+            operation.custom(
+                &mut FittedColumns((self.fitted_widths)(renderer)),
+                Some(&self.id.0),
+            );
+        }
+
+        if let Some((row, column)) = focus_cell {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                &mut FocusCell::new(row, column),
+            );
+        }
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(index) = state.copy_column.take() {
+            if let Some(text) = (self.column_text)(index) {
+                clipboard.write(clipboard::Kind::Standard, text.clone());
+
+                if let Some(on_copied) = &self.on_column_copied {
+                    shell.publish(on_copied(index, text));
+                }
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Root<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(root: Root<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(root)
+    }
+}
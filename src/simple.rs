@@ -0,0 +1,262 @@
+//! Declare basic columns from closures, without implementing [`Column`](table::Column).
+use std::fmt;
+
+use iced_core::{text, Element, Length};
+use iced_widget::{Space, Text};
+
+use crate::table;
+
+/// Creates a [`Vec`] of [`SimpleColumn`]s from `(title, width, cell)` tuples.
+///
+/// The `cell` closures take a reference to the row, which has to be annotated.
+///
+/// ```no_run
+/// # use iced_table::SimpleColumn;
+/// # use iced_widget::{text, Renderer, Theme};
+/// # struct Player { name: String, score: u32 }
+/// let columns = iced_table::columns![
+///     ("Name", 160.0, |player: &Player| text(&player.name).into()),
+///     ("Score", 80.0, |player: &Player| text(player.score).into()),
+/// ];
+/// # let _: &[SimpleColumn<Player, (), Theme, Renderer>] = &columns;
+/// ```
+///
+/// The [`Table`](crate::Table) borrows its columns, so they are kept in the state of the
+/// application along with their widths.
+#[macro_export]
+macro_rules! columns {
+    ($(($title:expr, $width:expr, $cell:expr $(,)?)),* $(,)?) => {
+        vec![$($crate::simple::SimpleColumn::new($title, $width, $cell)),*]
+    };
+}
+
+/// The [`Element`] of a cell, built from the index of a row & the row.
+type Cell<Row, Message, Theme, Renderer> =
+    Box<dyn for<'b> Fn(usize, &'b Row) -> Element<'b, Message, Theme, Renderer>>;
+
+/// The [`Element`] of a footer, built from all the rows.
+type Footer<Row, Message, Theme, Renderer> =
+    Box<dyn for<'b> Fn(&'b [Row]) -> Element<'b, Message, Theme, Renderer>>;
+
+/// A [`Column`](table::Column) with a text header and cells built by a closure.
+pub struct SimpleColumn<Row, Message, Theme, Renderer> {
+    title: String,
+    cell: Cell<Row, Message, Theme, Renderer>,
+    width: f32,
+    default_width: f32,
+}
+
+impl<Row, Message, Theme, Renderer> SimpleColumn<Row, Message, Theme, Renderer> {
+    /// Creates a new [`SimpleColumn`] with the given title & width, building the cell of
+    /// each row with `cell`.
+    pub fn new(
+        title: impl Into<String>,
+        width: f32,
+        cell: impl for<'b> Fn(&'b Row) -> Element<'b, Message, Theme, Renderer> + 'static,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            cell: Box::new(move |_row_index, row| cell(row)),
+            width,
+            default_width: width,
+        }
+    }
+
+    /// Sets the width of a resized [`SimpleColumn`], as emitted by
+    /// [`on_column_resize`](crate::Table::on_column_resize).
+    pub fn set_width(&mut self, width: f32) {
+        self.width = width;
+    }
+}
+
+impl<Row, Message, Theme, Renderer> fmt::Debug for SimpleColumn<Row, Message, Theme, Renderer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimpleColumn")
+            .field("title", &self.title)
+            .field("width", &self.width)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Row, Message, Theme, Renderer> table::Column<'a, Message, Theme, Renderer>
+    for SimpleColumn<Row, Message, Theme, Renderer>
+where
+    Row: 'a,
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    type Row = Row;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        Text::new(self.title.as_str()).into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        row_index: usize,
+        row: &'a Self::Row,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        (self.cell)(row_index, row)
+    }
+
+    fn default_width(&self) -> Option<f32> {
+        Some(self.default_width)
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+}
+
+/// A [`Column`](table::Column) defined with builder methods.
+///
+/// ```no_run
+/// # use iced_table::ColumnDef;
+/// # use iced_widget::text;
+/// # struct Player { name: String }
+/// let name = ColumnDef::new()
+///     .header("Name")
+///     .cell(|player: &Player| text(&player.name).into())
+///     .footer(|players| text!("{} players", players.len()).into())
+///     .width(160.0);
+/// # let _: ColumnDef<Player, ()> = name;
+/// ```
+///
+/// Its cells are empty until [`ColumnDef::cell`] is set.
+pub struct ColumnDef<Row, Message, Theme = iced_widget::Theme, Renderer = iced_widget::Renderer> {
+    header: String,
+    cell: Option<Cell<Row, Message, Theme, Renderer>>,
+    footer: Option<Footer<Row, Message, Theme, Renderer>>,
+    width: f32,
+    default_width: f32,
+}
+
+impl<Row, Message, Theme, Renderer> ColumnDef<Row, Message, Theme, Renderer> {
+    /// Creates a new [`ColumnDef`] without a header, with empty cells.
+    pub fn new() -> Self {
+        Self {
+            header: String::new(),
+            cell: None,
+            footer: None,
+            width: 100.0,
+            default_width: 100.0,
+        }
+    }
+
+    /// Sets the text of the header of the [`ColumnDef`].
+    pub fn header(self, header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            ..self
+        }
+    }
+
+    /// Sets the closure building the cell of each row.
+    pub fn cell(
+        self,
+        cell: impl for<'b> Fn(&'b Row) -> Element<'b, Message, Theme, Renderer> + 'static,
+    ) -> Self {
+        Self {
+            cell: Some(Box::new(move |_row_index, row| cell(row))),
+            ..self
+        }
+    }
+
+    /// Sets the closure building the cell of each row from its index, such as to emit
+    /// messages about the row.
+    pub fn indexed_cell(
+        self,
+        cell: impl for<'b> Fn(usize, &'b Row) -> Element<'b, Message, Theme, Renderer> + 'static,
+    ) -> Self {
+        Self {
+            cell: Some(Box::new(cell)),
+            ..self
+        }
+    }
+
+    /// Sets the closure building the footer from all the rows, shown once the
+    /// [`footer`](crate::Table::footer) of the [`Table`](crate::Table) is enabled.
+    pub fn footer(
+        self,
+        footer: impl for<'b> Fn(&'b [Row]) -> Element<'b, Message, Theme, Renderer> + 'static,
+    ) -> Self {
+        Self {
+            footer: Some(Box::new(footer)),
+            ..self
+        }
+    }
+
+    /// Sets the width of the [`ColumnDef`], which it is also reset to with the divider,
+    /// see [`on_column_reset`](crate::Table::on_column_reset).
+    pub fn width(self, width: f32) -> Self {
+        Self {
+            width,
+            default_width: width,
+            ..self
+        }
+    }
+
+    /// Sets the width of a resized [`ColumnDef`], as emitted by
+    /// [`on_column_resize`](crate::Table::on_column_resize).
+    pub fn set_width(&mut self, width: f32) {
+        self.width = width;
+    }
+}
+
+impl<Row, Message, Theme, Renderer> Default for ColumnDef<Row, Message, Theme, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Row, Message, Theme, Renderer> fmt::Debug for ColumnDef<Row, Message, Theme, Renderer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColumnDef")
+            .field("header", &self.header)
+            .field("width", &self.width)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Row, Message, Theme, Renderer> table::Column<'a, Message, Theme, Renderer>
+    for ColumnDef<Row, Message, Theme, Renderer>
+where
+    Row: 'a,
+    Theme: iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    type Row = Row;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        Text::new(self.header.as_str()).into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        row_index: usize,
+        row: &'a Self::Row,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        match &self.cell {
+            Some(cell) => cell(row_index, row),
+            None => Space::with_width(Length::Shrink).into(),
+        }
+    }
+
+    fn footer(
+        &'a self,
+        _col_index: usize,
+        rows: &'a [Self::Row],
+    ) -> Option<Element<'a, Message, Theme, Renderer>> {
+        self.footer.as_ref().map(|footer| footer(rows))
+    }
+
+    fn default_width(&self) -> Option<f32> {
+        Some(self.default_width)
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+}
@@ -0,0 +1,150 @@
+use std::f32::consts::TAU;
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::Cursor;
+use iced_core::time::Instant;
+use iced_core::widget::{self, Widget};
+use iced_core::{event, renderer, text, window, Background, Clipboard, Shell};
+use iced_core::{Color, Element, Length, Rectangle, Size};
+
+use crate::style;
+
+/// How long a shimmer of the placeholders lasts, in seconds.
+const PERIOD: f32 = 1.2;
+
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    started: Option<Instant>,
+    now: Option<Instant>,
+}
+
+impl State {
+    // The opacity of the placeholder, shimmering between dim & full
+    fn opacity(&self) -> f32 {
+        let elapsed = match self.started.zip(self.now) {
+            Some((started, now)) => now.saturating_duration_since(started).as_secs_f32(),
+            None => 0.0,
+        };
+
+        0.7 + 0.3 * (elapsed * TAU / PERIOD).cos()
+    }
+}
+
+/// A placeholder bar, the height of a line of text, shimmering while it is visible.
+pub(crate) struct Skeleton<'a, Theme>
+where
+    Theme: style::Catalog,
+{
+    class: style::Shared<'a, Theme>,
+}
+
+impl<'a, Theme> Skeleton<'a, Theme>
+where
+    Theme: style::Catalog,
+{
+    pub fn new(class: style::Shared<'a, Theme>) -> Self {
+        Self { class }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Skeleton<'a, Theme>
+where
+    Renderer: text::Renderer,
+    Theme: style::Catalog,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, Length::Fill, renderer.default_size())
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.started.get_or_insert(now);
+            state.now = Some(now);
+
+            // Only animate while visible
+            if layout.bounds().intersects(viewport) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.style(&self.class, style::Status::Skeleton);
+
+        let background = match appearance.background {
+            Some(Background::Color(color)) => Background::Color(Color {
+                a: color.a * state.opacity(),
+                ..color
+            }),
+            Some(background) => background,
+            None => return,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                shadow: Default::default(),
+            },
+            background,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Skeleton<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer + 'a,
+    Theme: style::Catalog + 'a,
+{
+    fn from(skeleton: Skeleton<'a, Theme>) -> Self {
+        Element::new(skeleton)
+    }
+}
@@ -0,0 +1,132 @@
+use std::cmp::Ordering;
+
+use crate::table::SortOrder;
+
+/// The columns rows are sorted by, in order of priority.
+///
+/// Sorting by a column replaces the sort keys, while appending it, such as when its
+/// header is shift-clicked with [`Table::on_sort_append`](crate::Table::on_sort_append),
+/// adds it as a secondary key. Columns report their [`order`](Self::order) &
+/// [`rank`](Self::rank) through [`Column::sort_order`](crate::table::Column::sort_order)
+/// & [`Column::sort_rank`](crate::table::Column::sort_rank).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SortModel {
+    keys: Vec<(usize, SortOrder)>,
+}
+
+impl SortModel {
+    /// Creates a new [`SortModel`] without any sort key.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the sort keys, as the index of their column & their order, by priority.
+    pub fn keys(&self) -> &[(usize, SortOrder)] {
+        &self.keys
+    }
+
+    /// Sorts by the column only, in the given order.
+    pub fn sort(&mut self, col_index: usize, order: SortOrder) {
+        self.keys = vec![(col_index, order)];
+    }
+
+    /// Adds the column as the last sort key, or changes its order if it's already one.
+    pub fn append(&mut self, col_index: usize, order: SortOrder) {
+        match self.keys.iter_mut().find(|(index, _)| *index == col_index) {
+            Some((_, current)) => *current = order,
+            None => self.keys.push((col_index, order)),
+        }
+    }
+
+    /// Removes all the sort keys.
+    pub fn clear(&mut self) {
+        self.keys.clear();
+    }
+
+    /// Returns the order the column is sorted in, if it's a sort key.
+    pub fn order(&self, col_index: usize) -> Option<SortOrder> {
+        self.keys
+            .iter()
+            .find(|(index, _)| *index == col_index)
+            .map(|(_, order)| *order)
+    }
+
+    /// Returns the priority of the column among the sort keys, starting from 1, when rows
+    /// are sorted by several columns.
+    pub fn rank(&self, col_index: usize) -> Option<usize> {
+        if self.keys.len() < 2 {
+            return None;
+        }
+
+        self.keys
+            .iter()
+            .position(|(index, _)| *index == col_index)
+            .map(|position| position + 1)
+    }
+
+    /// Compares two rows by each sort key in turn, given how `compare` orders them by a
+    /// column in ascending order.
+    ///
+    /// This is meant to be passed to [`slice::sort_by`].
+    pub fn compare<Row>(
+        &self,
+        a: &Row,
+        b: &Row,
+        compare: impl Fn(usize, &Row, &Row) -> Ordering,
+    ) -> Ordering {
+        self.keys
+            .iter()
+            .map(|&(col_index, order)| {
+                let ordering = compare(col_index, a, b);
+
+                match order {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_by_each_key_in_turn() {
+        let mut model = SortModel::new();
+        model.sort(0, SortOrder::Ascending);
+        model.append(1, SortOrder::Descending);
+
+        let mut rows = vec![(2, 'a'), (1, 'a'), (2, 'b'), (1, 'c')];
+        rows.sort_by(|a, b| {
+            model.compare(a, b, |col_index, a, b| match col_index {
+                0 => a.0.cmp(&b.0),
+                _ => a.1.cmp(&b.1),
+            })
+        });
+
+        assert_eq!(rows, vec![(1, 'c'), (1, 'a'), (2, 'b'), (2, 'a')]);
+    }
+
+    #[test]
+    fn rank_only_with_several_keys() {
+        let mut model = SortModel::new();
+        model.sort(2, SortOrder::Ascending);
+
+        assert_eq!(model.rank(2), None);
+
+        model.append(0, SortOrder::Ascending);
+        model.append(2, SortOrder::Descending);
+
+        assert_eq!(model.rank(2), Some(1));
+        assert_eq!(model.rank(0), Some(2));
+        assert_eq!(model.rank(1), None);
+        assert_eq!(model.order(2), Some(SortOrder::Descending));
+
+        model.sort(1, SortOrder::Descending);
+
+        assert_eq!(model.keys(), &[(1, SortOrder::Descending)]);
+    }
+}
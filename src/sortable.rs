@@ -0,0 +1,114 @@
+//! Display a header which sorts its column when clicked.
+use std::rc::Rc;
+
+use iced_core::{text, Element, Length};
+use iced_widget::{container, row, Text};
+
+use crate::clickable::Clickable;
+use crate::style;
+use crate::table::SortOrder;
+
+/// Creates a [`SortableHeader`] with the given title, showing the `current` [`SortOrder`]
+/// of its column with an arrow styled by the [`Status::SortIndicator`](style::Status) of
+/// its class.
+///
+/// Clicking it produces `on_toggle` with the [next](SortOrder::cycle) order, cycling
+/// through ascending, descending and unsorted. This suits consumers sorting their rows
+/// themselves, instead of with [`Table::on_sort`](crate::Table::on_sort).
+pub fn sortable_header<'a, Message, Theme, Renderer>(
+    title: impl text::IntoFragment<'a>,
+    current: Option<SortOrder>,
+    on_toggle: impl Fn(Option<SortOrder>) -> Message + 'a,
+) -> SortableHeader<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog + iced_widget::text::Catalog,
+    Renderer: text::Renderer,
+{
+    SortableHeader::new(title, current, on_toggle)
+}
+
+/// A header which sorts its column when clicked, see [`sortable_header`].
+#[allow(missing_debug_implementations)]
+pub struct SortableHeader<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog + iced_widget::text::Catalog,
+    Renderer: text::Renderer,
+{
+    title: Text<'a, Theme, Renderer>,
+    current: Option<SortOrder>,
+    on_toggle: Box<dyn Fn(Option<SortOrder>) -> Message + 'a>,
+    class: <Theme as style::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> SortableHeader<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog + iced_widget::text::Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`SortableHeader`], see [`sortable_header`].
+    pub fn new(
+        title: impl text::IntoFragment<'a>,
+        current: Option<SortOrder>,
+        on_toggle: impl Fn(Option<SortOrder>) -> Message + 'a,
+    ) -> Self {
+        Self {
+            title: Text::new(title),
+            current,
+            on_toggle: Box::new(on_toggle),
+            class: <Theme as style::Catalog>::default(),
+        }
+    }
+
+    /// Sets the style of the sort indicator, such as the one of the [`Table`](crate::Table).
+    pub fn style(self, style: impl Fn(&Theme, style::Status) -> container::Style + 'a) -> Self
+    where
+        <Theme as style::Catalog>::Class<'a>: From<style::StyleFn<'a, Theme>>,
+    {
+        Self {
+            class: (Rc::new(style) as style::StyleFn<'a, Theme>).into(),
+            ..self
+        }
+    }
+
+    /// Sets the style class of the sort indicator, such as the one of the
+    /// [`Table`](crate::Table).
+    pub fn class(self, class: impl Into<<Theme as style::Catalog>::Class<'a>>) -> Self {
+        Self {
+            class: class.into(),
+            ..self
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SortableHeader<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: style::Catalog + container::Catalog + iced_widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(header: SortableHeader<'a, Message, Theme, Renderer>) -> Self {
+        let SortableHeader {
+            title,
+            current,
+            on_toggle,
+            class,
+        } = header;
+
+        let title = container(title).width(Length::Fill);
+
+        let content: Element<'a, Message, Theme, Renderer> = match current {
+            Some(order) => row![
+                title,
+                style::wrapper::sort_indicator(Text::new(order.indicator()), Rc::new(class)),
+            ]
+            .spacing(4)
+            .into(),
+            None => title.into(),
+        };
+
+        Clickable::new(content)
+            .on_press(move |_| on_toggle(SortOrder::cycle(current)))
+            .into()
+    }
+}
@@ -0,0 +1,184 @@
+use std::cell::Cell;
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::{self, Operation, Tree, Widget};
+use iced_core::{event, overlay, renderer, window, Clipboard, Shell};
+use iced_core::{Element, Length, Rectangle, Size, Vector};
+
+/// The number of rows & cells built, counted by the builders of the rows as they run,
+/// which is once laid out for the rows of a virtualized body.
+#[derive(Debug, Default)]
+pub(crate) struct Built {
+    rows: Cell<usize>,
+    cells: Cell<usize>,
+}
+
+impl Built {
+    pub fn row(&self, cells: usize) {
+        self.rows.set(self.rows.get() + 1);
+        self.cells.set(self.cells.get() + cells);
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows.get()
+    }
+
+    pub fn cells(&self) -> usize {
+        self.cells.get()
+    }
+}
+
+/// Publishes a message once, with the first event other than a redraw processed after
+/// the view is built.
+///
+/// Redraws are skipped since publishing a message rebuilds the view, which would then be
+/// redrawn and publish again.
+///
+/// The message is produced when published, after the content is laid out, so it can
+/// account for what was built meanwhile.
+///
+/// The wrapper is transparent: its widget tree is the one of its content.
+pub(crate) struct Reporter<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    message: Option<Box<dyn FnOnce() -> Message + 'a>>,
+}
+
+impl<'a, Message, Theme, Renderer> Reporter<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        message: impl FnOnce() -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            message: Some(Box::new(message)),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Reporter<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if !matches!(
+            event,
+            event::Event::Window(window::Event::RedrawRequested(_))
+        ) {
+            if let Some(message) = self.message.take() {
+                shell.publish(message());
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Reporter<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(reporter: Reporter<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(reporter)
+    }
+}
@@ -1,70 +1,264 @@
-use iced_widget::container;
+//! Change the appearance of a [`Table`](crate::Table).
+use std::rc::Rc;
 
-/// A set of rules that dictate the styling of a [`Table`](crate::Table).
+use iced_core::{Border, Color, Theme};
+use iced_widget::container::Style;
+
+/// The part of a [`Table`](crate::Table) being styled, along with its status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The header.
+    Header,
+    /// The footer.
+    Footer,
+    /// A row, given its index and whether it is hovered by the cursor or selected.
+    Row {
+        /// The index of the row.
+        index: usize,
+        /// The total number of rows of the table.
+        total: usize,
+        /// Whether the row is hovered by the cursor.
+        hovered: bool,
+        /// Whether the row is among the
+        /// [`selected_rows`](crate::Table::selected_rows).
+        selected: bool,
+    },
+    /// The divider between two columns.
+    Divider {
+        /// Whether the divider is hovered by the cursor.
+        hovered: bool,
+        /// Whether the divider is being dragged.
+        dragged: bool,
+    },
+    /// The vertical lines between the columns of the body, drawn with the width & color of
+    /// the border.
+    Gridline,
+    /// The tooltip of a cell.
+    Tooltip,
+    /// The band shown in place of rows which failed to load.
+    Error,
+    /// A row displayed as a single element spanning the full width of the table, see
+    /// [`RowKind::FullWidth`](crate::table::RowKind::FullWidth).
+    FullWidthRow,
+    /// The header band of a group of rows, see [`Table::group_by`](crate::Table::group_by).
+    RowGroup,
+    /// A subtotal band.
+    Subtotal,
+    /// The grand total band.
+    GrandTotal,
+    /// The cell with keyboard focus, drawn over its content.
+    FocusedCell,
+    /// The cell being edited.
+    EditingCell,
+    /// The selection, drawn over selected cells.
+    Selection,
+    /// The indicator shown in the header of a sorted column.
+    SortIndicator,
+    /// The line showing where a dragged column will be dropped.
+    DropIndicator,
+    /// The placeholders in the cells of the loading rows.
+    Skeleton,
+}
+
+/// The style of a row, as returned by [`Table::row_style`](crate::Table::row_style).
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Copy, Default)]
+pub enum RowStyle {
+    /// The [`Status::Row`] style of the [`Catalog`].
+    #[default]
+    Default,
+    /// A custom [`Style`], used while the row isn't hovered nor selected.
+    Custom(Style),
+}
+
+/// The theme catalog of a [`Table`](crate::Table).
 pub trait Catalog {
-    /// The supported style of the [`Catalog`].
-    type Style: Default + Clone;
-
-    /// The header [`Style`](iced_widget::container::Style) of the [`Catalog`].
-    fn header(&self, style: &Self::Style) -> container::Style;
-    /// The footer [`Style`](iced_widget::container::Style) of the [`Catalog`].
-    fn footer(&self, style: &Self::Style) -> container::Style;
-    /// The row [`Style`](iced_widget::container::Style) of the [`Catalog`].
-    fn row(&self, style: &Self::Style, index: usize) -> container::Style;
-    /// The divider [`Style`](iced_widget::container::Style) of the [`Catalog`].
-    fn divider(&self, style: &Self::Style, hovered: bool) -> container::Style;
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
 }
 
-impl Catalog for iced_core::Theme {
-    type Style = ();
+/// A styling function for a [`Table`](crate::Table).
+pub type StyleFn<'a, Theme> = Rc<dyn Fn(&Theme, Status) -> Style + 'a>;
 
-    fn header(&self, _style: &Self::Style) -> container::Style {
-        container::Style {
-            text_color: Some(self.extended_palette().background.strong.text),
-            background: Some(self.extended_palette().background.strong.color.into()),
-            ..Default::default()
-        }
+// The class of a table, shared by its parts instead of cloned for each of them
+pub(crate) type Shared<'a, Theme> = Rc<<Theme as Catalog>::Class<'a>>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Rc::new(default)
     }
 
-    fn footer(&self, style: &Self::Style) -> container::Style {
-        self.header(style)
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
     }
+}
 
-    fn row(&self, _style: &Self::Style, index: usize) -> container::Style {
-        let pair = if index % 2 == 0 {
-            self.extended_palette().background.base
-        } else {
-            self.extended_palette().background.weak
-        };
+/// The default style of a [`Table`](crate::Table).
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
 
-        container::Style {
-            text_color: Some(pair.text),
-            background: Some(pair.color.into()),
+    match status {
+        Status::Header | Status::Footer | Status::GrandTotal | Status::RowGroup => Style {
+            text_color: Some(palette.background.strong.text),
+            background: Some(palette.background.strong.color.into()),
             ..Default::default()
+        },
+        Status::Row {
+            index,
+            hovered,
+            selected,
+            ..
+        } => {
+            let pair = if selected {
+                palette.primary.strong
+            } else if hovered {
+                palette.primary.weak
+            } else if index % 2 == 0 {
+                palette.background.base
+            } else {
+                palette.background.weak
+            };
+
+            Style {
+                text_color: Some(pair.text),
+                background: Some(pair.color.into()),
+                ..Default::default()
+            }
+        }
+        Status::Divider { hovered, dragged } => {
+            let pair = if hovered || dragged {
+                palette.primary.base
+            } else {
+                palette.background.weak
+            };
+
+            Style {
+                background: Some(pair.color.into()),
+                ..Default::default()
+            }
         }
+        Status::Gridline => Style {
+            border: Border {
+                color: palette.background.strong.color,
+                width: 1.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        Status::Tooltip => iced_widget::container::rounded_box(theme),
+        Status::Error => Style {
+            text_color: Some(palette.danger.weak.text),
+            background: Some(palette.danger.weak.color.into()),
+            ..Default::default()
+        },
+        Status::FullWidthRow => Style {
+            text_color: Some(palette.secondary.base.text),
+            background: Some(palette.secondary.base.color.into()),
+            ..Default::default()
+        },
+        Status::Subtotal => Style {
+            text_color: Some(palette.secondary.weak.text),
+            background: Some(palette.secondary.weak.color.into()),
+            ..Default::default()
+        },
+        Status::FocusedCell => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                width: 2.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        Status::EditingCell => Style {
+            text_color: Some(palette.background.base.text),
+            background: Some(palette.background.base.color.into()),
+            border: Border {
+                color: palette.primary.base.color,
+                width: 2.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        Status::Selection => Style {
+            background: Some(
+                Color {
+                    a: 0.3,
+                    ..palette.primary.weak.color
+                }
+                .into(),
+            ),
+            ..Default::default()
+        },
+        Status::SortIndicator => Style {
+            text_color: Some(palette.background.strong.text),
+            ..Default::default()
+        },
+        Status::DropIndicator => Style {
+            background: Some(palette.primary.strong.color.into()),
+            ..Default::default()
+        },
+        Status::Skeleton => Style {
+            background: Some(palette.background.strong.color.into()),
+            border: Border {
+                radius: 4.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
     }
+}
 
-    fn divider(&self, _style: &Self::Style, hovered: bool) -> container::Style {
-        let pair = if hovered {
-            self.extended_palette().primary.base
-        } else {
-            self.extended_palette().background.weak
-        };
+/// The [`default`] style of a [`Table`](crate::Table), with its rows striped in bands of
+/// `interval` rows instead of alternating, such as `2` to stripe them in pairs.
+///
+/// ```no_run
+/// # let columns: Vec<iced_table::ColumnDef<String, ()>> = Vec::new();
+/// # let rows: Vec<String> = Vec::new();
+/// # let _: iced_core::Element<'_, (), iced_widget::Theme, iced_widget::Renderer> =
+/// iced_table::table(&columns, &rows).style(iced_table::style::banded(3))
+/// # .into();
+/// ```
+pub fn banded(interval: usize) -> impl Fn(&Theme, Status) -> Style {
+    let interval = interval.max(1);
 
-        container::Style {
-            background: Some(pair.color.into()),
-            ..Default::default()
-        }
+    move |theme, status| match status {
+        Status::Row {
+            index,
+            total,
+            hovered,
+            selected,
+        } => default(
+            theme,
+            Status::Row {
+                index: index / interval,
+                total,
+                hovered,
+                selected,
+            },
+        ),
+        _ => default(theme, status),
     }
 }
 
 pub(crate) mod wrapper {
-    use iced_core::{mouse::Cursor, Color, Element, Length, Size, Vector, Widget};
+    use iced_core::{
+        mouse::Cursor, Background, Border, Color, Element, Length, Rectangle, Size, Vector, Widget,
+    };
     use iced_widget::container;
 
+    use crate::table::SummaryLevel;
+
     pub fn header<'a, Message, Theme, Renderer>(
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
-        style: <Theme as super::Catalog>::Style,
+        class: super::Shared<'a, Theme>,
     ) -> Element<'a, Message, Theme, Renderer>
     where
         Renderer: iced_core::Renderer + 'a,
@@ -74,14 +268,14 @@ pub(crate) mod wrapper {
         Wrapper {
             content: content.into(),
             target: Target::Header,
-            style,
+            class,
         }
         .into()
     }
 
     pub fn footer<'a, Message, Theme, Renderer>(
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
-        style: <Theme as super::Catalog>::Style,
+        class: super::Shared<'a, Theme>,
     ) -> Element<'a, Message, Theme, Renderer>
     where
         Renderer: iced_core::Renderer + 'a,
@@ -91,15 +285,146 @@ pub(crate) mod wrapper {
         Wrapper {
             content: content.into(),
             target: Target::Footer,
-            style,
+            class,
         }
         .into()
     }
 
+    /// What the appearance of a row depends on, besides being hovered.
+    #[derive(Clone, Copy)]
+    pub struct StyledRow {
+        pub index: usize,
+        pub total: usize,
+        pub style: super::RowStyle,
+        pub selected: bool,
+    }
+
+    impl StyledRow {
+        /// A row of the default style, which isn't selected, out of `total` rows.
+        pub fn new(index: usize, total: usize) -> Self {
+            Self {
+                index,
+                total,
+                style: super::RowStyle::Default,
+                selected: false,
+            }
+        }
+    }
+
+    /// `merged` rows leave painting their background to the enclosing [`body`].
     pub fn row<'a, Message, Theme, Renderer>(
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
-        style: <Theme as super::Catalog>::Style,
-        index: usize,
+        class: super::Shared<'a, Theme>,
+        row: StyledRow,
+        merged: bool,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::Row { row, merged },
+            class,
+        }
+        .into()
+    }
+
+    pub fn error<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::Error,
+            class,
+        }
+        .into()
+    }
+
+    pub fn full_width_row<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::FullWidthRow,
+            class,
+        }
+        .into()
+    }
+
+    pub fn row_group<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::RowGroup,
+            class,
+        }
+        .into()
+    }
+
+    pub fn summary<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+        level: SummaryLevel,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::Summary(level),
+            class,
+        }
+        .into()
+    }
+
+    /// Draws a vertical gridline over its content, centered in the `divider_width` at its
+    /// right edge.
+    pub fn gridline<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+        divider_width: f32,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::Gridline { divider_width },
+            class,
+        }
+        .into()
+    }
+
+    /// Paints the given style of a cell under its content.
+    pub fn cell<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+        style: container::Style,
     ) -> Element<'a, Message, Theme, Renderer>
     where
         Renderer: iced_core::Renderer + 'a,
@@ -108,33 +433,254 @@ pub(crate) mod wrapper {
     {
         Wrapper {
             content: content.into(),
-            target: Target::Row { index },
-            style,
+            target: Target::Cell(style),
+            class,
         }
         .into()
     }
 
+    /// Draws the selection over its content.
+    pub fn selected<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::Selected,
+            class,
+        }
+        .into()
+    }
+
+    /// Draws the focus over its content.
+    pub fn focused<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::Focused,
+            class,
+        }
+        .into()
+    }
+
+    /// Paints the background of the cell being edited under its content.
+    pub fn editing<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::Editing,
+            class,
+        }
+        .into()
+    }
+
+    pub fn sort_indicator<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::SortIndicator,
+            class,
+        }
+        .into()
+    }
+
+    /// Paints the backgrounds of its `merged` rows, merging adjacent rows of the same
+    /// appearance into a single quad. `rows` are the [`StyledRow`] of each child row, or
+    /// `None` for children which paint their own background.
+    pub fn body<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        class: super::Shared<'a, Theme>,
+        rows: Vec<Option<StyledRow>>,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::Body { rows },
+            class,
+        }
+        .into()
+    }
+
+    #[allow(clippy::large_enum_variant)]
     enum Target {
         Header,
         Footer,
-        Row { index: usize },
+        Row { row: StyledRow, merged: bool },
+        Error,
+        FullWidthRow,
+        RowGroup,
+        Summary(SummaryLevel),
+        Cell(container::Style),
+        Gridline { divider_width: f32 },
+        Selected,
+        Focused,
+        Editing,
+        SortIndicator,
+        Body { rows: Vec<Option<StyledRow>> },
     }
 
     impl Target {
         fn appearance<Theme>(
             &self,
             theme: &Theme,
-            style: &<Theme as super::Catalog>::Style,
+            class: &<Theme as super::Catalog>::Class<'_>,
+            hovered: bool,
         ) -> container::Style
         where
             Theme: super::Catalog,
         {
-            match self {
-                Target::Header => theme.header(style),
-                Target::Footer => theme.footer(style),
-                Target::Row { index } => theme.row(style, *index),
+            use super::Status;
+
+            let status = match self {
+                Target::Header => Status::Header,
+                Target::Footer => Status::Footer,
+                Target::Row { row, .. } => return row_appearance(theme, class, *row, hovered),
+                Target::Error => Status::Error,
+                Target::FullWidthRow => Status::FullWidthRow,
+                Target::RowGroup => Status::RowGroup,
+                Target::Summary(SummaryLevel::Subtotal) => Status::Subtotal,
+                Target::Summary(SummaryLevel::GrandTotal) => Status::GrandTotal,
+                Target::Selected => Status::Selection,
+                Target::Focused => Status::FocusedCell,
+                Target::Editing => Status::EditingCell,
+                Target::SortIndicator => Status::SortIndicator,
+                Target::Gridline { .. } => Status::Gridline,
+                Target::Cell(style) => return *style,
+                Target::Body { .. } => return container::Style::default(),
+            };
+
+            theme.style(class, status)
+        }
+    }
+
+    fn row_appearance<Theme>(
+        theme: &Theme,
+        class: &<Theme as super::Catalog>::Class<'_>,
+        row: StyledRow,
+        hovered: bool,
+    ) -> container::Style
+    where
+        Theme: super::Catalog,
+    {
+        match row.style {
+            super::RowStyle::Custom(style) if !hovered && !row.selected => style,
+            _ => theme.style(
+                class,
+                super::Status::Row {
+                    index: row.index,
+                    total: row.total,
+                    hovered,
+                    selected: row.selected,
+                },
+            ),
+        }
+    }
+
+    fn fill_quad<Renderer>(
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        appearance: &container::Style,
+    ) where
+        Renderer: iced_core::Renderer,
+    {
+        let has_background = match appearance.background {
+            Some(Background::Color(color)) => color.a > 0.0,
+            Some(Background::Gradient(_)) => true,
+            None => false,
+        };
+        let has_border = appearance.border.width > 0.0 && appearance.border.color.a > 0.0;
+
+        // Skip painting fully transparent quads
+        if has_background || has_border {
+            renderer.fill_quad(
+                iced_core::renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    shadow: Default::default(),
+                },
+                appearance
+                    .background
+                    .unwrap_or_else(|| Color::TRANSPARENT.into()),
+            );
+        }
+    }
+
+    fn fill_merged_rows<Theme, Renderer>(
+        renderer: &mut Renderer,
+        theme: &Theme,
+        class: &<Theme as super::Catalog>::Class<'_>,
+        layout: iced_core::Layout<'_>,
+        rows: &[Option<StyledRow>],
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) where
+        Renderer: iced_core::Renderer,
+        Theme: super::Catalog,
+    {
+        let mut run: Option<(Rectangle, container::Style)> = None;
+
+        for (row, bounds) in rows.iter().zip(layout.children().map(|row| row.bounds())) {
+            let Some(row) = row else {
+                continue;
+            };
+
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            let appearance = row_appearance(theme, class, *row, cursor.is_over(bounds));
+
+            match &mut run {
+                Some((run_bounds, run_appearance))
+                    if run_appearance.background == appearance.background
+                        && run_appearance.border == appearance.border
+                        && appearance.border == Border::default()
+                        && (run_bounds.y + run_bounds.height - bounds.y).abs() < 0.5 =>
+                {
+                    *run_bounds = run_bounds.union(&bounds);
+                }
+                _ => {
+                    if let Some((bounds, appearance)) = run.take() {
+                        fill_quad(renderer, bounds, &appearance);
+                    }
+                    run = Some((bounds, appearance));
+                }
             }
         }
+
+        if let Some((bounds, appearance)) = run {
+            fill_quad(renderer, bounds, &appearance);
+        }
     }
 
     struct Wrapper<'a, Message, Theme, Renderer>
@@ -144,7 +690,7 @@ pub(crate) mod wrapper {
     {
         content: Element<'a, Message, Theme, Renderer>,
         target: Target,
-        style: <Theme as super::Catalog>::Style,
+        class: super::Shared<'a, Theme>,
     }
 
     impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -176,18 +722,23 @@ pub(crate) mod wrapper {
             cursor: Cursor,
             viewport: &iced_core::Rectangle,
         ) {
-            let appearance = self.target.appearance::<Theme>(theme, &self.style);
+            if !layout.bounds().intersects(viewport) {
+                return;
+            }
 
-            renderer.fill_quad(
-                iced_core::renderer::Quad {
-                    bounds: layout.bounds(),
-                    border: appearance.border,
-                    shadow: Default::default(),
-                },
-                appearance
-                    .background
-                    .unwrap_or_else(|| Color::TRANSPARENT.into()),
-            );
+            let hovered = cursor.is_over(layout.bounds());
+            let appearance = self.target.appearance(theme, &self.class, hovered);
+
+            match &self.target {
+                Target::Body { rows } => {
+                    fill_merged_rows(renderer, theme, &self.class, layout, rows, cursor, viewport);
+                }
+                Target::Row { merged: true, .. }
+                | Target::Selected
+                | Target::Focused
+                | Target::Gridline { .. } => {}
+                _ => fill_quad(renderer, layout.bounds(), &appearance),
+            }
 
             let style = appearance
                 .text_color
@@ -196,7 +747,31 @@ pub(crate) mod wrapper {
 
             self.content
                 .as_widget()
-                .draw(state, renderer, theme, &style, layout, cursor, viewport)
+                .draw(state, renderer, theme, &style, layout, cursor, viewport);
+
+            match self.target {
+                Target::Selected | Target::Focused => {
+                    fill_quad(renderer, layout.bounds(), &appearance);
+                }
+                Target::Gridline { divider_width } => {
+                    let bounds = layout.bounds();
+                    let width = appearance.border.width;
+
+                    fill_quad(
+                        renderer,
+                        Rectangle {
+                            x: bounds.x + bounds.width - (divider_width + width) / 2.0,
+                            width,
+                            ..bounds
+                        },
+                        &container::Style {
+                            background: Some(appearance.border.color.into()),
+                            ..Default::default()
+                        },
+                    );
+                }
+                _ => {}
+            }
         }
 
         fn tag(&self) -> iced_core::widget::tree::Tag {
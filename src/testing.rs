@@ -0,0 +1,492 @@
+//! Lay out and interact with a [`Table`] without a window, for testing table configurations.
+//!
+//! The [`Table`] is laid out with the null [`Renderer`] of [`iced_core`], so the
+//! [`Column`](crate::table::Column) implementations under test must be generic over
+//! the renderer, or implemented for [`Renderer`]. Text measures as zero sized with this
+//! renderer, which keeps snapshots stable across platforms and fonts.
+//!
+//! A [`Column`](crate::table::Column) which is generic over the renderer can be shared
+//! by the application and its tests, without pulling a graphics backend into the tests:
+//!
+//! ```no_run
+//! # use iced_core::Element;
+//! # use iced_table::table;
+//! # use iced_widget::text;
+//! # struct Column;
+//! impl<'a, Message, Theme, Renderer> table::Column<'a, Message, Theme, Renderer> for Column
+//! where
+//!     Theme: iced_widget::text::Catalog + 'a,
+//!     Renderer: iced_core::text::Renderer + 'a,
+//! {
+//!     // ...
+//! #   type Row = String;
+//! #
+//! #   fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+//! #       text("Name").into()
+//! #   }
+//! #
+//! #   fn cell(&'a self, _: usize, _: usize, row: &'a String) -> Element<'a, Message, Theme, Renderer> {
+//! #       text(row).into()
+//! #   }
+//! #
+//! #   fn width(&self) -> f32 {
+//! #       100.0
+//! #   }
+//! }
+//! ```
+use iced_core::keyboard::{self, key};
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::{self, Cursor};
+use iced_core::renderer;
+use iced_core::widget::{Operation, Tree};
+use iced_core::{clipboard, event, Clipboard, Element, Point, Rectangle, Shell, Size, Vector};
+use iced_widget::{button, container, scrollable, text};
+
+use crate::style;
+use crate::table::{self, Table};
+
+pub use iced_core::Theme;
+
+/// The null renderer of [`iced_core`], which measures text as zero sized and draws nothing.
+pub type Renderer = ();
+
+/// The horizontal position of a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColumnBounds {
+    /// The x position of the left edge of the column.
+    pub x: f32,
+    /// The width of the column, including its divider.
+    pub width: f32,
+}
+
+/// The layout of a [`Table`], as returned by [`snapshot`].
+///
+/// All bounds are absolute, with the body rows laid out as if the body was
+/// scrolled to the top.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    /// The bounds of the whole [`Table`].
+    pub bounds: Rectangle,
+    /// The bounds of the header band, if the [`Table`] has a [header](Table::header).
+    pub header: Option<Rectangle>,
+    /// The bounds of the band of rows pinned above the body, if any, see
+    /// [`pin_rows_top`](Table::pin_rows_top).
+    pub pinned_top: Option<Rectangle>,
+    /// The bounds of the body band, which scrolls the rows.
+    pub body: Rectangle,
+    /// The bounds of the band of rows pinned below the body, if any, see
+    /// [`pin_rows_bottom`](Table::pin_rows_bottom).
+    pub pinned_bottom: Option<Rectangle>,
+    /// The bounds of the footer band, if the [`Table`] has a footer.
+    pub footer: Option<Rectangle>,
+    /// The position of each displayed column, in order, taken from the footer without a
+    /// header. Empty without both.
+    pub columns: Vec<ColumnBounds>,
+    /// The bounds of each displayed row, in display order. Rows which
+    /// [failed to load](Table::failed_rows) are replaced by a single band per range. The
+    /// rows of a [`virtualize`](Table::virtualize)d body out of view keep their bounds.
+    pub rows: Vec<Rectangle>,
+}
+
+/// Lays out the [`Table`] within the given `size` and returns a [`Snapshot`] of the result.
+pub fn snapshot<'a, Column, Row, Message, Theme>(
+    table: Table<'a, Column, Row, Message, Theme>,
+    size: impl Into<Size>,
+) -> Snapshot
+where
+    Theme: style::Catalog
+        + container::Catalog
+        + scrollable::Catalog
+        + text::Catalog
+        + button::Catalog
+        + 'a,
+    Column: table::Column<'a, Message, Theme, Renderer, Row = Row>,
+    Message: 'a + Clone,
+{
+    Simulator::new(table, size).snapshot()
+}
+
+/// Lays out and draws an [`Element`], such as a [`Column::cell`](crate::table::Column::cell),
+/// within the given `size` and returns the size it takes.
+///
+/// This is useful to smoke test [`Column`](crate::table::Column) implementations in
+/// plain `cargo test`.
+pub fn render<'a, Message, Theme>(
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    theme: &Theme,
+    size: impl Into<Size>,
+) -> Size {
+    let element = element.into();
+    let size = size.into();
+
+    let mut tree = Tree::new(&element);
+
+    let node = element
+        .as_widget()
+        .layout(&mut tree, &(), &layout::Limits::new(Size::ZERO, size));
+
+    element.as_widget().draw(
+        &tree,
+        &mut (),
+        theme,
+        &renderer::Style::default(),
+        Layout::new(&node),
+        Cursor::Unavailable,
+        &Rectangle::with_size(size),
+    );
+
+    node.size()
+}
+
+/// Feeds synthetic mouse & keyboard events to a [`Table`] and collects the messages it
+/// produces, to test interactions such as column resizing.
+///
+/// Positions are absolute, as in a [`Snapshot`]. The messages are not applied to the
+/// [`Table`]; it must be rebuilt from the updated state and passed to
+/// [`update`](Simulator::update) to observe their effect.
+#[allow(missing_debug_implementations)]
+pub struct Simulator<'a, Message, Theme> {
+    element: Element<'a, Message, Theme, Renderer>,
+    tree: Tree,
+    size: Size,
+    node: layout::Node,
+    column_count: usize,
+    has_header: bool,
+    has_pinned_rows: (bool, bool),
+    has_max_body_height: bool,
+    has_column_groups: bool,
+    cursor: Cursor,
+    clipboard: Recorder,
+    messages: Vec<Message>,
+}
+
+impl<'a, Message, Theme> Simulator<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: 'a,
+{
+    /// Creates a new [`Simulator`] laying out the [`Table`] within the given `size`.
+    pub fn new<Column, Row>(
+        table: Table<'a, Column, Row, Message, Theme>,
+        size: impl Into<Size>,
+    ) -> Self
+    where
+        Theme: style::Catalog
+            + container::Catalog
+            + scrollable::Catalog
+            + text::Catalog
+            + button::Catalog,
+        Column: table::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: Clone,
+    {
+        let column_count = table.column_count::<Renderer>();
+        let has_header = table.has_header();
+        let has_pinned_rows = table.has_pinned_rows();
+        let has_max_body_height = table.has_max_body_height();
+        let has_column_groups = table.has_column_groups::<Renderer>();
+        let size = size.into();
+
+        let element: Element<'a, Message, Theme, Renderer> = table.into();
+        let mut tree = Tree::new(&element);
+
+        let node =
+            element
+                .as_widget()
+                .layout(&mut tree, &(), &layout::Limits::new(Size::ZERO, size));
+
+        Self {
+            element,
+            tree,
+            size,
+            node,
+            column_count,
+            has_header,
+            has_pinned_rows,
+            has_max_body_height,
+            has_column_groups,
+            cursor: Cursor::Unavailable,
+            clipboard: Recorder::default(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Replaces the [`Table`] with a new view of it, such as one rebuilt from the state
+    /// updated with the messages, keeping the state of its widgets as an application does.
+    pub fn update<Column, Row>(&mut self, table: Table<'a, Column, Row, Message, Theme>)
+    where
+        Theme: style::Catalog
+            + container::Catalog
+            + scrollable::Catalog
+            + text::Catalog
+            + button::Catalog,
+        Column: table::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: Clone,
+    {
+        self.column_count = table.column_count::<Renderer>();
+        self.has_header = table.has_header();
+        self.has_pinned_rows = table.has_pinned_rows();
+        self.has_max_body_height = table.has_max_body_height();
+        self.has_column_groups = table.has_column_groups::<Renderer>();
+        self.element = table.into();
+
+        self.tree.diff(&self.element);
+        self.node = self.element.as_widget().layout(
+            &mut self.tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, self.size),
+        );
+    }
+
+    /// Returns a [`Snapshot`] of the current layout of the [`Table`].
+    pub fn snapshot(&self) -> Snapshot {
+        let layout = Layout::new(&self.node);
+        let bands = self.bands();
+
+        let body_rows = bands.body.children().next().expect("body has rows");
+
+        Snapshot {
+            bounds: layout.bounds(),
+            header: bands.header.map(|header| header.bounds()),
+            pinned_top: bands.pinned_top.map(|pinned| pinned.bounds()),
+            body: bands.body.bounds(),
+            pinned_bottom: bands.pinned_bottom.map(|pinned| pinned.bounds()),
+            footer: bands.footer.map(|footer| footer.bounds()),
+            columns: self
+                .column_cells()
+                .into_iter()
+                .flat_map(|cells| cells.children())
+                .take(self.column_count)
+                .map(|cell| {
+                    let bounds = cell.bounds();
+
+                    ColumnBounds {
+                        x: bounds.x,
+                        width: bounds.width,
+                    }
+                })
+                .collect(),
+            rows: body_rows.children().map(|row| row.bounds()).collect(),
+        }
+    }
+
+    // The scrollables of the table, each wrapping a single child
+    fn bands(&self) -> Bands<'_> {
+        let (has_pinned_top, has_pinned_bottom) = self.has_pinned_rows;
+        let mut bands = Layout::new(&self.node).children();
+
+        let header = if self.has_header { bands.next() } else { None };
+        let pinned_top = if has_pinned_top { bands.next() } else { None };
+        let body = bands.next().expect("table has a body");
+        let body = if self.has_max_body_height {
+            body.children().next().expect("body has a scrollable")
+        } else {
+            body
+        };
+        let pinned_bottom = if has_pinned_bottom {
+            bands.next()
+        } else {
+            None
+        };
+        let footer = bands.next();
+
+        Bands {
+            header,
+            pinned_top,
+            body,
+            pinned_bottom,
+            footer,
+        }
+    }
+
+    // The headers of the columns, below the headers of their groups, if any, or the
+    // footers of the columns without a header
+    fn column_cells(&self) -> Option<Layout<'_>> {
+        let bands = self.bands();
+
+        let Some(header) = bands.header else {
+            return bands.footer?.children().next();
+        };
+
+        let header_cells = header.children().next()?;
+
+        if self.has_column_groups {
+            header_cells.children().last()
+        } else {
+            Some(header_cells)
+        }
+    }
+
+    /// Processes a single [`Event`](event::Event) with the current cursor position.
+    pub fn event(&mut self, event: event::Event) -> event::Status {
+        let mut shell = Shell::new(&mut self.messages);
+
+        let status = self.element.as_widget_mut().on_event(
+            &mut self.tree,
+            event,
+            Layout::new(&self.node),
+            self.cursor,
+            &(),
+            &mut self.clipboard,
+            &mut shell,
+            &Rectangle::with_size(self.size),
+        );
+
+        if shell.is_layout_invalid() {
+            self.node = self.element.as_widget().layout(
+                &mut self.tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, self.size),
+            );
+        }
+
+        status
+    }
+
+    /// Runs the [`Operation`] on the [`Table`], such as one of [`operation`](crate::operation).
+    ///
+    /// Wrap typed operations with [`black_box`](iced_core::widget::operation::black_box)
+    /// and read their [`Outcome`](iced_core::widget::operation::Outcome) afterwards.
+    pub fn operate(&mut self, operation: &mut dyn Operation) {
+        self.element
+            .as_widget()
+            .operate(&mut self.tree, Layout::new(&self.node), &(), operation);
+    }
+
+    /// Moves the cursor to the given position.
+    pub fn move_cursor(&mut self, position: impl Into<Point>) {
+        let position = position.into();
+
+        self.cursor = Cursor::Available(position);
+        self.event(event::Event::Mouse(mouse::Event::CursorMoved { position }));
+    }
+
+    /// Presses and releases the left mouse button at the given position.
+    pub fn click(&mut self, position: impl Into<Point>) {
+        self.move_cursor(position);
+        self.event(event::Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        self.event(event::Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+    }
+
+    /// Drags the cursor with the left mouse button held from `from` to `to`.
+    pub fn drag(&mut self, from: impl Into<Point>, to: impl Into<Point>) {
+        self.move_cursor(from);
+        self.event(event::Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        self.move_cursor(to);
+        self.event(event::Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+    }
+
+    /// Drags the header divider of the column at `index` horizontally by `offset`, or its
+    /// footer divider without a header.
+    ///
+    /// # Panics
+    /// Panics if there is no column at `index`.
+    pub fn drag_divider(&mut self, index: usize, offset: f32) {
+        let snapshot = self.snapshot();
+        let column = snapshot.columns[index];
+
+        let from = Point::new(
+            column.x + column.width - 1.0,
+            self.column_cells()
+                .expect("table has a header or footer")
+                .bounds()
+                .center_y(),
+        );
+
+        self.drag(from, from + Vector::new(offset, 0.0));
+    }
+
+    /// Clicks the center of the displayed row at `index`, assuming the body isn't scrolled.
+    ///
+    /// # Panics
+    /// Panics if there is no displayed row at `index`.
+    pub fn click_row(&mut self, index: usize) {
+        let row = self.snapshot().rows[index];
+
+        self.click(row.center());
+    }
+
+    /// Scrolls the wheel by the given amount of pixels at the current cursor position.
+    pub fn scroll(&mut self, delta: impl Into<Vector>) {
+        let delta = delta.into();
+
+        self.event(event::Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels {
+                x: delta.x,
+                y: delta.y,
+            },
+        }));
+    }
+
+    /// Presses and releases the given [`Key`](keyboard::Key) with the given
+    /// [`Modifiers`](keyboard::Modifiers) held.
+    pub fn press_key(&mut self, key: keyboard::Key, modifiers: keyboard::Modifiers) {
+        self.event(event::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            modified_key: key.clone(),
+            physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+            location: keyboard::Location::Standard,
+            modifiers,
+            text: None,
+        }));
+        self.event(event::Event::Keyboard(keyboard::Event::KeyReleased {
+            key,
+            location: keyboard::Location::Standard,
+            modifiers,
+        }));
+    }
+
+    /// Returns the text last written to the standard clipboard.
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.standard.as_deref()
+    }
+
+    /// Returns the messages produced so far.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Returns the messages produced so far, clearing them from the [`Simulator`].
+    pub fn take_messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+}
+
+// The layouts of the scrollables of a table
+struct Bands<'a> {
+    header: Option<Layout<'a>>,
+    pinned_top: Option<Layout<'a>>,
+    body: Layout<'a>,
+    pinned_bottom: Option<Layout<'a>>,
+    footer: Option<Layout<'a>>,
+}
+
+// Keeps what is written to the clipboard, for inspection
+#[derive(Debug, Default)]
+struct Recorder {
+    standard: Option<String>,
+    primary: Option<String>,
+}
+
+impl Clipboard for Recorder {
+    fn read(&self, kind: clipboard::Kind) -> Option<String> {
+        match kind {
+            clipboard::Kind::Standard => self.standard.clone(),
+            clipboard::Kind::Primary => self.primary.clone(),
+        }
+    }
+
+    fn write(&mut self, kind: clipboard::Kind, contents: String) {
+        match kind {
+            clipboard::Kind::Standard => self.standard = Some(contents),
+            clipboard::Kind::Primary => self.primary = Some(contents),
+        }
+    }
+}
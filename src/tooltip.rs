@@ -0,0 +1,423 @@
+use iced_core::layout::{self, Layout};
+use iced_core::mouse::Cursor;
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{self, Widget};
+use iced_core::{event, overlay, renderer, window, Clipboard, Shell};
+use iced_core::{Color, Element, Length, Padding, Point, Rectangle, Size, Vector};
+use iced_widget::tooltip::Position;
+
+use crate::style;
+
+/// Tracks how long an element has been hovered for, to show a tooltip after a delay.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum Hover {
+    #[default]
+    Idle,
+    Hovered {
+        since: Instant,
+        cursor_position: Point,
+    },
+}
+
+impl Hover {
+    fn is_visible(&self, delay: Duration, now: Instant) -> bool {
+        match self {
+            Hover::Idle => false,
+            Hover::Hovered { since, .. } => now.saturating_duration_since(*since) >= delay,
+        }
+    }
+
+    /// Returns the cursor position if the tooltip should currently be visible.
+    pub fn visible(&self, delay: Duration) -> Option<Point> {
+        match self {
+            Hover::Hovered {
+                cursor_position, ..
+            } if self.is_visible(delay, Instant::now()) => Some(*cursor_position),
+            _ => None,
+        }
+    }
+
+    pub fn update<Message>(
+        &mut self,
+        event: &event::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+        delay: Duration,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let now = match event {
+            event::Event::Window(window::Event::RedrawRequested(now)) => *now,
+            _ => Instant::now(),
+        };
+        let was_visible = self.is_visible(delay, now);
+
+        *self = match (cursor.position_over(bounds), *self) {
+            (Some(cursor_position), Hover::Hovered { since, .. }) => Hover::Hovered {
+                since,
+                cursor_position,
+            },
+            (Some(cursor_position), Hover::Idle) => Hover::Hovered {
+                since: now,
+                cursor_position,
+            },
+            (None, _) => Hover::Idle,
+        };
+
+        let is_visible = self.is_visible(delay, now);
+
+        if let Hover::Hovered { since, .. } = *self {
+            if !is_visible {
+                shell.request_redraw(window::RedrawRequest::At(since + delay));
+            }
+        }
+
+        if was_visible != is_visible {
+            shell.invalidate_layout();
+        }
+    }
+}
+
+pub(crate) struct Tooltip<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    tooltip: Element<'a, Message, Theme, Renderer>,
+    position: Position,
+    delay: Duration,
+    class: style::Shared<'a, Theme>,
+}
+
+impl<'a, Message, Theme, Renderer> Tooltip<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+        position: Position,
+        delay: Duration,
+        class: style::Shared<'a, Theme>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            tooltip: tooltip.into(),
+            position,
+            delay,
+            class,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tooltip<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<Hover>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(Hover::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![
+            widget::Tree::new(&self.content),
+            widget::Tree::new(&self.tooltip),
+        ]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[&self.content, &self.tooltip]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        tree.state.downcast_mut::<Hover>().update(
+            &event,
+            layout.bounds(),
+            cursor,
+            self.delay,
+            shell,
+        );
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> iced_core::mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let hover = *tree.state.downcast_ref::<Hover>();
+
+        let mut children = tree.children.iter_mut();
+
+        let content = self.content.as_widget_mut().overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+            translation,
+        );
+
+        let tooltip = hover.visible(self.delay).map(|cursor_position| {
+            overlay::Element::new(Box::new(Overlay::new(
+                layout.position() + translation,
+                &self.tooltip,
+                children.next().unwrap(),
+                cursor_position,
+                layout.bounds(),
+                self.position,
+                &self.class,
+            )))
+        });
+
+        if content.is_some() || tooltip.is_some() {
+            Some(
+                overlay::Group::with_children(content.into_iter().chain(tooltip).collect())
+                    .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tooltip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: style::Catalog + 'a,
+{
+    fn from(tooltip: Tooltip<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tooltip)
+    }
+}
+
+/// The floating [`overlay`] of a tooltip.
+pub(crate) struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+{
+    position: Point,
+    tooltip: &'b Element<'a, Message, Theme, Renderer>,
+    state: &'b mut widget::Tree,
+    cursor_position: Point,
+    content_bounds: Rectangle,
+    positioning: Position,
+    class: &'b <Theme as style::Catalog>::Class<'a>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + 'b,
+    Theme: style::Catalog + 'b,
+    Message: 'b,
+{
+    const PADDING: f32 = 5.0;
+
+    pub fn new(
+        position: Point,
+        tooltip: &'b Element<'a, Message, Theme, Renderer>,
+        state: &'b mut widget::Tree,
+        cursor_position: Point,
+        content_bounds: Rectangle,
+        positioning: Position,
+        class: &'b <Theme as style::Catalog>::Class<'a>,
+    ) -> Self {
+        Self {
+            position,
+            tooltip,
+            state,
+            cursor_position,
+            content_bounds,
+            positioning,
+            class,
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let padding = Self::PADDING;
+        let viewport = Rectangle::with_size(bounds);
+
+        let tooltip_layout = self.tooltip.as_widget().layout(
+            self.state,
+            renderer,
+            &layout::Limits::new(Size::ZERO, viewport.size()).shrink(Padding::new(padding)),
+        );
+
+        let size = tooltip_layout.bounds().size();
+        let x_center = self.position.x + (self.content_bounds.width - size.width) / 2.0;
+        let y_center = self.position.y + (self.content_bounds.height - size.height) / 2.0;
+
+        let offset = match self.positioning {
+            Position::Top => Vector::new(x_center, self.position.y - size.height - padding),
+            Position::Bottom => Vector::new(
+                x_center,
+                self.position.y + self.content_bounds.height + padding,
+            ),
+            Position::Left => Vector::new(self.position.x - size.width - padding, y_center),
+            Position::Right => Vector::new(
+                self.position.x + self.content_bounds.width + padding,
+                y_center,
+            ),
+            Position::FollowCursor => {
+                let translation = self.position - self.content_bounds.position();
+
+                Vector::new(self.cursor_position.x, self.cursor_position.y - size.height)
+                    + translation
+            }
+        };
+
+        // Keep the tooltip inside the window
+        let width = size.width + padding * 2.0;
+        let height = size.height + padding * 2.0;
+        let x = (offset.x - padding).min(viewport.width - width).max(0.0);
+        let y = (offset.y - padding).min(viewport.height - height).max(0.0);
+
+        layout::Node::with_children(
+            Size::new(width, height),
+            vec![tooltip_layout.translate(Vector::new(padding, padding))],
+        )
+        .translate(Vector::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let appearance = theme.style(self.class, style::Status::Tooltip);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border: appearance.border,
+                shadow: appearance.shadow,
+            },
+            appearance
+                .background
+                .unwrap_or_else(|| Color::TRANSPARENT.into()),
+        );
+
+        let style = appearance
+            .text_color
+            .map(|text_color| renderer::Style { text_color })
+            .unwrap_or(*style);
+
+        self.tooltip.as_widget().draw(
+            self.state,
+            renderer,
+            theme,
+            &style,
+            layout.children().next().unwrap(),
+            cursor,
+            &Rectangle::with_size(Size::INFINITY),
+        );
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        false
+    }
+}
@@ -0,0 +1,405 @@
+use iced_core::keyboard::{self, key};
+use iced_core::widget::operation::{self, Outcome};
+use iced_core::widget::Operation;
+use iced_core::{Element, Vector};
+use iced_table::table::{self, CellEdit, Id, SortOrder};
+use iced_table::testing::{Renderer, Simulator, Theme};
+use iced_table::{operation as table_operation, Table};
+use iced_widget::text;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Resized(usize, f32),
+    Sort(usize, SortOrder),
+    CellClicked(usize, usize),
+    RowReordered(usize, usize),
+    ColumnReordered(usize, usize),
+    Focus(usize, usize),
+    Edit(CellEdit),
+    Copied(usize, String),
+}
+
+// Text measures as zero sized with the null renderer, so the content is given a height
+struct Column;
+
+impl<'a> table::Column<'a, Message, Theme, Renderer> for Column {
+    type Row = u32;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        text("Header").height(20).into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        _row_index: usize,
+        row: &'a u32,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        text(row).height(10).into()
+    }
+
+    fn footer(
+        &'a self,
+        _col_index: usize,
+        _rows: &'a [u32],
+    ) -> Option<Element<'a, Message, Theme, Renderer>> {
+        Some(text("Footer").height(10).into())
+    }
+
+    fn cell_text(&self, col_index: usize, _row_index: usize, row: &u32) -> Option<String> {
+        Some(format!("{col_index}:{row}"))
+    }
+
+    fn width(&self) -> f32 {
+        100.0
+    }
+
+    fn is_editable(&self) -> bool {
+        true
+    }
+}
+
+const COLUMNS: [Column; 5] = [Column, Column, Column, Column, Column];
+
+// A column whose header is given its height, to change the header between views
+struct HeaderColumn {
+    height: f32,
+    width: f32,
+}
+
+impl<'a> table::Column<'a, Message, Theme, Renderer> for HeaderColumn {
+    type Row = u32;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        text("Header").height(self.height).into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        _row_index: usize,
+        row: &'a u32,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        text(row).height(10).into()
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+}
+
+// The header is 28 high & each row 18, with the default padding
+const BODY_Y: f32 = 28.0;
+const ROW_HEIGHT: f32 = 18.0;
+
+fn row_y(index: usize) -> f32 {
+    BODY_Y + ROW_HEIGHT * index as f32 + ROW_HEIGHT / 2.0
+}
+
+fn simulator<'a>(table: Table<'a, Column, u32, Message, Theme>) -> Simulator<'a, Message, Theme> {
+    Simulator::new(table, (300.0, 200.0))
+}
+
+#[test]
+fn resize_column() {
+    let rows: Vec<u32> = (0..10).collect();
+    let mut simulator = simulator(table::table(&COLUMNS, &rows).on_column_resize(Message::Resized));
+
+    simulator.drag_divider(1, 40.0);
+
+    assert_eq!(simulator.take_messages(), vec![Message::Resized(1, 140.0)]);
+}
+
+#[test]
+fn header_follows_the_body() {
+    let rows: Vec<u32> = (0..10).collect();
+    let mut simulator = simulator(table::table(&COLUMNS, &rows).on_sort(Message::Sort));
+
+    simulator.click((150.0, 10.0));
+    simulator.move_cursor((150.0, row_y(2)));
+    simulator.scroll(Vector::new(-100.0, 0.0));
+    simulator.click((150.0, 10.0));
+
+    assert_eq!(
+        simulator.take_messages(),
+        vec![
+            Message::Sort(1, SortOrder::Ascending),
+            Message::Sort(2, SortOrder::Ascending)
+        ]
+    );
+}
+
+#[test]
+fn pinned_rows_follow_the_body() {
+    let rows: Vec<u32> = (0..10).collect();
+    let pinned = [4];
+    let mut simulator = simulator(
+        table::table(&COLUMNS, &rows)
+            .pin_rows_top(&pinned)
+            .on_cell_click(Message::CellClicked),
+    );
+
+    simulator.move_cursor((150.0, row_y(4)));
+    simulator.scroll(Vector::new(-100.0, 0.0));
+    simulator.click((150.0, row_y(0)));
+
+    assert_eq!(simulator.take_messages(), vec![Message::CellClicked(4, 2)]);
+}
+
+#[test]
+fn click_with_a_capturing_handler() {
+    let rows: Vec<u32> = (0..10).collect();
+    let page = 3;
+    let mut simulator = simulator(
+        table::table(&COLUMNS, &rows)
+            .on_cell_click(move |row, column| Message::CellClicked(page * 10 + row, column)),
+    );
+
+    simulator.click((250.0, row_y(1)));
+
+    assert_eq!(simulator.take_messages(), vec![Message::CellClicked(31, 2)]);
+}
+
+#[test]
+fn reorder_rows_and_columns() {
+    let rows: Vec<u32> = (0..10).collect();
+    let mut simulator = simulator(
+        table::table(&COLUMNS, &rows)
+            .on_row_reorder(Message::RowReordered)
+            .on_column_reorder(Message::ColumnReordered),
+    );
+
+    simulator.drag((50.0, row_y(0)), (50.0, row_y(2) + 5.0));
+    simulator.drag((50.0, 10.0), (160.0, 10.0));
+
+    assert_eq!(
+        simulator.take_messages(),
+        vec![Message::RowReordered(0, 2), Message::ColumnReordered(0, 1)]
+    );
+}
+
+#[test]
+fn navigate_cells() {
+    let rows: Vec<u32> = (0..10).collect();
+    let mut simulator = simulator(
+        table::table(&COLUMNS, &rows)
+            .on_cell_focus(Message::Focus)
+            .focused_cell(Some((3, 1))),
+    );
+
+    for named in [
+        key::Named::ArrowDown,
+        key::Named::ArrowRight,
+        key::Named::ArrowUp,
+        key::Named::ArrowLeft,
+    ] {
+        simulator.press_key(keyboard::Key::Named(named), keyboard::Modifiers::empty());
+    }
+
+    assert_eq!(
+        simulator.take_messages(),
+        vec![
+            Message::Focus(4, 1),
+            Message::Focus(3, 2),
+            Message::Focus(2, 1),
+            Message::Focus(3, 0),
+        ]
+    );
+}
+
+#[test]
+fn edit_cells() {
+    let rows: Vec<u32> = (0..10).collect();
+    let mut simulator = simulator(
+        table::table(&COLUMNS, &rows)
+            .on_cell_edit(Message::Edit)
+            .editing_cell(Some((1, 4))),
+    );
+
+    simulator.press_key(
+        keyboard::Key::Named(key::Named::Tab),
+        keyboard::Modifiers::empty(),
+    );
+    simulator.press_key(
+        keyboard::Key::Named(key::Named::Escape),
+        keyboard::Modifiers::empty(),
+    );
+
+    assert_eq!(
+        simulator.take_messages(),
+        vec![
+            Message::Edit(CellEdit::Commit { row: 1, column: 4 }),
+            Message::Edit(CellEdit::Start { row: 2, column: 0 }),
+            Message::Edit(CellEdit::Cancel { row: 1, column: 4 }),
+        ]
+    );
+}
+
+#[test]
+fn keep_anchored_rows_in_view() {
+    let id = Id::new("anchored");
+    let rows: Vec<u32> = (100..200).collect();
+    let inserted: Vec<u32> = (95..200).collect();
+
+    let table = |rows| {
+        table::table(&COLUMNS, rows)
+            .id(id.clone())
+            .row_key(|row: &u32| u64::from(*row))
+            .anchor_rows(true)
+    };
+
+    let mut simulator = simulator(table(&rows));
+
+    simulator.move_cursor((150.0, row_y(2)));
+    simulator.scroll(Vector::new(0.0, -10.0 * ROW_HEIGHT));
+
+    assert_eq!(scroll_offset_y(&mut simulator, &id), 10.0 * ROW_HEIGHT);
+
+    // Laid out again, the body is kept scrolled until the next event
+    simulator.update(table(&inserted));
+
+    assert_eq!(scroll_offset_y(&mut simulator, &id), 10.0 * ROW_HEIGHT);
+
+    simulator.move_cursor((150.0, row_y(2)));
+
+    assert_eq!(scroll_offset_y(&mut simulator, &id), 15.0 * ROW_HEIGHT);
+}
+
+#[test]
+fn relayout_cached_bands() {
+    let rows: Vec<u32> = (0..10).collect();
+    let short = header_columns(20.0, 100.0);
+    let tall = header_columns(40.0, 100.0);
+    let wide = header_columns(40.0, 150.0);
+
+    let mut simulator = Simulator::new(table::table(&short, &rows).cache_bands(0), (300.0, 200.0));
+
+    assert_eq!(header_height(&simulator), 28.0);
+
+    // The layout is kept for as long as the generation is the same
+    simulator.update(table::table(&tall, &rows).cache_bands(0));
+
+    assert_eq!(header_height(&simulator), 28.0);
+
+    simulator.update(table::table(&tall, &rows).cache_bands(1));
+
+    assert_eq!(header_height(&simulator), 48.0);
+    assert_eq!(simulator.snapshot().rows[0].y, 48.0);
+
+    // Along with the widths of the columns
+    simulator.update(table::table(&wide, &rows).cache_bands(1));
+
+    assert_eq!(simulator.snapshot().columns[0].width, 150.0);
+
+    // Along with the widgets of the header, such as the dividers
+    simulator.update(
+        table::table(&wide, &rows)
+            .cache_bands(1)
+            .on_column_resize(Message::Resized),
+    );
+    simulator.drag_divider(0, 10.0);
+
+    assert_eq!(simulator.take_messages(), vec![Message::Resized(0, 160.0)]);
+}
+
+#[test]
+fn operate_on_the_table() {
+    let id = Id::new("operated");
+    let rows: Vec<u32> = (0..100).collect();
+    let mut simulator = simulator(table::table(&COLUMNS, &rows).id(id.clone()));
+
+    let mut widths = table_operation::column_widths(id.clone());
+    simulator.operate(&mut operation::black_box(&mut widths));
+
+    assert!(matches!(
+        widths.finish(),
+        Outcome::Some(widths) if widths == vec![100.0; 5]
+    ));
+
+    simulator.operate(&mut table_operation::scroll_to(
+        id.clone(),
+        operation::scrollable::AbsoluteOffset { x: 0.0, y: 90.0 },
+    ));
+
+    assert_eq!(scroll_offset_y(&mut simulator, &id), 90.0);
+}
+
+#[test]
+fn copy_a_column() {
+    let id = Id::new("copied");
+    let rows: Vec<u32> = (0..3).collect();
+    let mut simulator = simulator(
+        table::table(&COLUMNS, &rows)
+            .id(id.clone())
+            .on_column_copied(Message::Copied),
+    );
+
+    // Out of range, nothing is copied
+    simulator.operate(&mut table_operation::copy_column(id.clone(), 5));
+    simulator.move_cursor((150.0, row_y(0)));
+
+    assert_eq!(simulator.clipboard(), None);
+    assert!(simulator.take_messages().is_empty());
+
+    simulator.operate(&mut table_operation::copy_column(id.clone(), 1));
+    simulator.move_cursor((150.0, row_y(0)));
+
+    assert_eq!(simulator.clipboard(), Some("1:0\n1:1\n1:2"));
+    assert_eq!(
+        simulator.take_messages(),
+        vec![Message::Copied(1, String::from("1:0\n1:1\n1:2"))]
+    );
+}
+
+#[test]
+fn copy_the_rows_of_an_iterator() {
+    let id = Id::new("iterated");
+    let rows: Vec<u32> = (0..3).collect();
+    let mut simulator =
+        simulator(iced_table::table_iter(&COLUMNS, rows.iter().rev()).id(id.clone()));
+
+    simulator.operate(&mut table_operation::copy_column(id.clone(), 0));
+    simulator.move_cursor((150.0, row_y(0)));
+
+    assert_eq!(simulator.clipboard(), Some("0:2\n0:1\n0:0"));
+}
+
+#[test]
+fn copy_the_selected_rows() {
+    let rows: Vec<u32> = (0..5).collect();
+    let selected = [3, 1];
+    let mut simulator = simulator(table::table(&COLUMNS[..2], &rows).selected_rows(&selected));
+
+    simulator.move_cursor((150.0, row_y(0)));
+    simulator.press_key(
+        keyboard::Key::Character("c".into()),
+        keyboard::Modifiers::COMMAND,
+    );
+
+    assert_eq!(simulator.clipboard(), Some("0:1\t1:1\n0:3\t1:3"));
+}
+
+fn header_columns(height: f32, width: f32) -> [HeaderColumn; 2] {
+    [
+        HeaderColumn { height, width },
+        HeaderColumn { height, width },
+    ]
+}
+
+fn header_height(simulator: &Simulator<'_, Message, Theme>) -> f32 {
+    simulator
+        .snapshot()
+        .header
+        .expect("table has a header")
+        .height
+}
+
+fn scroll_offset_y(simulator: &mut Simulator<'_, Message, Theme>, id: &Id) -> f32 {
+    let mut operation = table_operation::scroll_offset(id.clone());
+    simulator.operate(&mut operation::black_box(&mut operation));
+
+    match operation.finish() {
+        Outcome::Some(scroll) => scroll.offset.y,
+        _ => panic!("table has a body"),
+    }
+}
@@ -0,0 +1,209 @@
+use iced_core::{Element, Rectangle, Size};
+use iced_table::table;
+use iced_table::testing::{self, ColumnBounds, Renderer, Theme};
+use iced_widget::text;
+
+// Text measures as zero sized with the null renderer, so the content is given a height
+struct Column {
+    width: f32,
+}
+
+impl<'a> table::Column<'a, (), Theme, Renderer> for Column {
+    type Row = u32;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, (), Theme, Renderer> {
+        text("Header").height(20).into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        _row_index: usize,
+        row: &'a u32,
+    ) -> Element<'a, (), Theme, Renderer> {
+        text(row).height(10).into()
+    }
+
+    fn footer(
+        &'a self,
+        _col_index: usize,
+        _rows: &'a [u32],
+    ) -> Option<Element<'a, (), Theme, Renderer>> {
+        Some(text("Footer").height(30).into())
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+}
+
+const COLUMNS: [Column; 2] = [Column { width: 100.0 }, Column { width: 50.0 }];
+
+// A column of plain cells, whose cell is only built when the fast path is not taken
+struct PlainColumn;
+
+impl<'a> table::Column<'a, (), Theme, Renderer> for PlainColumn {
+    type Row = u32;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, (), Theme, Renderer> {
+        text("Header").height(20).into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        _row_index: usize,
+        row: &'a u32,
+    ) -> Element<'a, (), Theme, Renderer> {
+        text(row).height(10).into()
+    }
+
+    fn cell_text(&self, _col_index: usize, _row_index: usize, row: &u32) -> Option<String> {
+        Some(row.to_string())
+    }
+
+    fn width(&self) -> f32 {
+        100.0
+    }
+
+    fn is_plain(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn bands_columns_and_rows() {
+    let rows = [1, 2, 3];
+
+    let snapshot = testing::snapshot(table::table(&COLUMNS, &rows).footer(true), (400.0, 300.0));
+
+    assert_eq!(
+        snapshot.bounds,
+        Rectangle::new([0.0, 0.0].into(), Size::new(400.0, 300.0))
+    );
+    // The content height plus the default padding of 4 on both sides
+    assert_eq!(snapshot.header, Some(rectangle(0.0, 0.0, 150.0, 28.0)));
+    assert_eq!(snapshot.footer, Some(rectangle(0.0, 262.0, 150.0, 38.0)));
+    assert_eq!(snapshot.body, rectangle(0.0, 28.0, 150.0, 234.0));
+    assert_eq!(snapshot.pinned_top, None);
+    assert_eq!(snapshot.pinned_bottom, None);
+
+    assert_eq!(
+        snapshot.columns,
+        vec![
+            ColumnBounds {
+                x: 0.0,
+                width: 100.0
+            },
+            ColumnBounds {
+                x: 100.0,
+                width: 50.0
+            },
+        ]
+    );
+    assert_eq!(
+        snapshot.rows,
+        vec![
+            rectangle(0.0, 28.0, 150.0, 18.0),
+            rectangle(0.0, 46.0, 150.0, 18.0),
+            rectangle(0.0, 64.0, 150.0, 18.0),
+        ]
+    );
+}
+
+#[test]
+fn pinned_rows_and_min_width() {
+    let rows = [1, 2, 3];
+    let pinned = [0];
+
+    let snapshot = testing::snapshot(
+        table::table(&COLUMNS, &rows)
+            .pin_rows_top(&pinned)
+            .min_width(200.0),
+        (400.0, 300.0),
+    );
+
+    // The columns keep their width, with the remaining width left to a filler
+    assert_eq!(
+        snapshot.columns,
+        vec![
+            ColumnBounds {
+                x: 0.0,
+                width: 100.0
+            },
+            ColumnBounds {
+                x: 100.0,
+                width: 50.0
+            },
+        ]
+    );
+    assert_eq!(snapshot.header, Some(rectangle(0.0, 0.0, 200.0, 28.0)));
+    assert_eq!(snapshot.footer, None);
+    assert_eq!(snapshot.pinned_top, Some(rectangle(0.0, 28.0, 200.0, 18.0)));
+    assert_eq!(snapshot.body, rectangle(0.0, 46.0, 200.0, 254.0));
+    assert_eq!(snapshot.rows.len(), 3);
+    assert_eq!(snapshot.rows[0], rectangle(0.0, 46.0, 200.0, 18.0));
+}
+
+#[test]
+fn virtualized_rows_keep_their_bounds() {
+    let rows: Vec<u32> = (0..1000).collect();
+
+    let snapshot = testing::snapshot(
+        table::table(&COLUMNS, &rows).virtualize(|_, _| 18.0),
+        (400.0, 300.0),
+    );
+
+    assert_eq!(snapshot.rows.len(), 1000);
+    assert_eq!(
+        snapshot.rows[999],
+        rectangle(0.0, 28.0 + 999.0 * 18.0, 150.0, 18.0)
+    );
+}
+
+#[test]
+fn plain_cells_with_gridlines() {
+    let rows = [1, 2];
+    let columns = [PlainColumn];
+
+    let snapshot = testing::snapshot(
+        table::table(&columns, &rows).gridlines(true),
+        (400.0, 300.0),
+    );
+
+    // A plain cell is a line of text at the default size of 16, instead of the 10 high cell
+    let height = 16.0 * 1.3 + 8.0;
+
+    assert_eq!(snapshot.rows[0], rectangle(0.0, 28.0, 100.0, height));
+    assert_eq!(
+        snapshot.rows[1],
+        rectangle(0.0, 28.0 + height, 100.0, height)
+    );
+
+    // Focusing a cell leaves the fast path
+    let snapshot = testing::snapshot(
+        table::table(&columns, &rows)
+            .gridlines(true)
+            .focused_cell(Some((0, 0))),
+        (400.0, 300.0),
+    );
+
+    assert_eq!(snapshot.rows[0], rectangle(0.0, 28.0, 100.0, 18.0));
+}
+
+#[test]
+fn render_cell() {
+    let column = Column { width: 100.0 };
+
+    let size = testing::render(
+        table::Column::<(), Theme, Renderer>::cell(&column, 0, 0, &7),
+        &Theme::Light,
+        (100.0, 100.0),
+    );
+
+    assert_eq!(size, Size::new(0.0, 10.0));
+}
+
+fn rectangle(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+    Rectangle::new([x, y].into(), Size::new(width, height))
+}